@@ -0,0 +1,448 @@
+//! Compile-time parsing of std-style format strings.
+
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Error, Expr, Ident, LitBool, LitStr, Result, Token,
+};
+
+/// The input to `std_format_args!`: the path to `restd`, whether to add a
+/// trailing newline, the format string, and its arguments.
+pub struct Input {
+    krate: TokenTree,
+    newline: bool,
+    fmt: LitStr,
+    args: Vec<Arg>,
+}
+
+/// An argument, optionally named (e.g. `x = 5`).
+struct Arg {
+    name: Option<Ident>,
+    expr: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let krate = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let newline = input.parse::<LitBool>()?.value;
+        input.parse::<Token![,]>()?;
+        let fmt = input.parse()?;
+
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                Some(name)
+            } else {
+                None
+            };
+
+            args.push(Arg {
+                name,
+                expr: input.parse()?,
+            });
+        }
+
+        Ok(Self {
+            krate,
+            newline,
+            fmt,
+            args,
+        })
+    }
+}
+
+/// A reference to an argument from a placeholder.
+enum ArgRef {
+    Next,
+    Index(usize),
+    Name(String),
+}
+
+/// A parsed format spec, e.g. the `>8?` in `{x:>8?}`.
+#[derive(Default)]
+struct Spec {
+    fill: Option<char>,
+    align: Option<char>,
+    plus: bool,
+    alt: bool,
+    zero: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    ty: String,
+}
+
+/// A piece of a format string.
+enum Piece {
+    Lit(String),
+    Arg(ArgRef, Spec),
+}
+
+/// A piece of a format string, resolved to indices into the arguments and
+/// styles.
+enum Var {
+    Lit(String),
+    Arg {
+        arg: usize,
+        style: usize,
+
+        /// Whether or not the style is a pair to pick from by type.
+        pick: bool,
+
+        /// Whether or not the argument must be a number for the `+` flag.
+        sign: bool,
+    },
+}
+
+pub fn expand(input: Input) -> Result<TokenStream2> {
+    let Input {
+        krate,
+        newline,
+        fmt,
+        mut args,
+    } = input;
+    let span = fmt.span();
+    let err = |msg: String| Error::new(span, msg);
+
+    let mut pieces = parse(&fmt.value()).map_err(err)?;
+    if newline {
+        pieces.push(Piece::Lit("\n".into()));
+    }
+
+    let named = args.iter().position(|a| a.name.is_some());
+    if let Some(i) = named {
+        if args[i..].iter().any(|a| a.name.is_none()) {
+            return Err(Error::new_spanned(
+                &args[i..].iter().find(|a| a.name.is_none()).unwrap().expr,
+                "positional arguments cannot follow named arguments",
+            ));
+        }
+    }
+
+    // resolve each placeholder to an argument, capturing any missing names
+    let explicit = args.len();
+    let mut used = vec![false; explicit];
+    let mut next = 0;
+    let mut vars = Vec::new();
+    let mut styles = Vec::new();
+    for piece in pieces {
+        match piece {
+            Piece::Lit(lit) => vars.push(Var::Lit(lit)),
+            Piece::Arg(arg, spec) => {
+                let positional = |i| match i < explicit {
+                    true => Ok(i),
+                    false => Err(err(format!(
+                        "invalid reference to positional argument {i} ({explicit} given)"
+                    ))),
+                };
+
+                let i = match arg {
+                    ArgRef::Next => {
+                        next += 1;
+                        positional(next - 1)?
+                    }
+                    ArgRef::Index(i) => positional(i)?,
+                    ArgRef::Name(name) => match args
+                        .iter()
+                        .position(|a| a.name.as_ref().is_some_and(|n| *n == name))
+                    {
+                        Some(i) => i,
+                        None => {
+                            let ident = Ident::new(&name, span);
+                            args.push(Arg {
+                                name: Some(ident.clone()),
+                                expr: syn::parse_quote!(#ident),
+                            });
+                            used.push(false);
+                            args.len() - 1
+                        }
+                    },
+                };
+
+                // like std, the defaults for width and precision depend on
+                // whether or not the argument is a number
+                let pick = matches!(spec.ty.as_str(), "" | "?")
+                    && (spec.width.is_some() || spec.precision.is_some());
+
+                used[i] = true;
+                vars.push(Var::Arg {
+                    arg: i,
+                    style: styles.len(),
+                    pick,
+                    sign: spec.plus,
+                });
+                styles.push(if pick {
+                    let number = style(&krate, &spec, true).map_err(err)?;
+                    let other = style(&krate, &spec, false).map_err(err)?;
+                    quote!((#number, #other))
+                } else {
+                    style(&krate, &spec, true).map_err(err)?
+                });
+            }
+        }
+    }
+
+    if let Some(i) = used[..explicit].iter().position(|u| !u) {
+        return Err(Error::new_spanned(
+            &args[i].expr,
+            "argument never used in format string",
+        ));
+    }
+
+    let arg_exprs = args.iter().map(|a| &a.expr);
+    let arg_binds = (0..args.len()).map(|i| format_ident!("__arg{}", i, span = Span::mixed_site()));
+    let style_binds =
+        (0..styles.len()).map(|i| format_ident!("__style{}", i, span = Span::mixed_site()));
+
+    let vars = vars.into_iter().map(|var| match var {
+        Var::Arg {
+            arg: i,
+            style,
+            pick,
+            sign,
+        } => {
+            let arg = format_ident!("__arg{}", i, span = Span::mixed_site());
+            let style = format_ident!("__style{}", style, span = Span::mixed_site());
+            let var = if pick {
+                quote!({
+                    use #krate::fmt::args::{PickNumber as _, PickOther as _};
+                    (&#krate::fmt::args::Pick(#arg)).var(#style)
+                })
+            } else {
+                quote!(#krate::fmt::args::Var::new(#arg, #style))
+            };
+
+            if sign {
+                // `Sign` is only implemented for numbers, so check for them
+                // first to give a clearer error
+                let check = quote_spanned!(args[i].expr.span()=>
+                    #krate::fmt::args::signed(#arg);
+                );
+                quote!({ #check #var })
+            } else {
+                var
+            }
+        }
+        Var::Lit(lit) => quote!(#krate::fmt::args::Var::new(&#lit, &#krate::fmt::Display)),
+    });
+
+    Ok(quote! {
+        #krate::fmt::args::Arguments(&match (#(&(#arg_exprs),)* #(&(#styles),)*) {
+            (#(#arg_binds,)* #(#style_binds,)*) => [#(#vars),*],
+        })
+    })
+}
+
+/// Splits a format string into literals and placeholders.
+fn parse(fmt: &str) -> core::result::Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut lit = String::new();
+    let mut chars = fmt.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                lit.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                lit.push('}');
+            }
+            '}' => return Err("unmatched `}` in format string; use `}}` to escape it".into()),
+            '{' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(ch) => inner.push(ch),
+                        None => {
+                            return Err(
+                                "unmatched `{` in format string; use `{{` to escape it".into()
+                            )
+                        }
+                    }
+                }
+
+                if !lit.is_empty() {
+                    pieces.push(Piece::Lit(core::mem::take(&mut lit)));
+                }
+
+                let (arg, spec) = inner.split_once(':').unwrap_or((&inner, ""));
+                pieces.push(Piece::Arg(parse_arg(arg)?, parse_spec(spec)?));
+            }
+            ch => lit.push(ch),
+        }
+    }
+
+    if !lit.is_empty() {
+        pieces.push(Piece::Lit(lit));
+    }
+
+    Ok(pieces)
+}
+
+fn parse_arg(arg: &str) -> core::result::Result<ArgRef, String> {
+    if arg.is_empty() {
+        Ok(ArgRef::Next)
+    } else if let Ok(i) = arg.parse() {
+        Ok(ArgRef::Index(i))
+    } else if arg.starts_with(|ch: char| ch.is_alphabetic() || ch == '_')
+        && arg.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+    {
+        Ok(ArgRef::Name(arg.into()))
+    } else {
+        Err(format!("invalid argument `{arg}` in format string"))
+    }
+}
+
+fn parse_spec(spec: &str) -> core::result::Result<Spec, String> {
+    let chars = spec.chars().collect::<Vec<_>>();
+    let mut out = Spec::default();
+    let mut i = 0;
+
+    let is_align = |ch: Option<&char>| matches!(ch, Some('<' | '^' | '>'));
+    if is_align(chars.get(1)) {
+        out.fill = Some(chars[0]);
+        out.align = Some(chars[1]);
+        i = 2;
+    } else if is_align(chars.first()) {
+        out.align = Some(chars[0]);
+        i = 1;
+    }
+
+    if chars.get(i) == Some(&'+') {
+        out.plus = true;
+        i += 1;
+    } else if chars.get(i) == Some(&'-') {
+        return Err("the `-` flag is unsupported".into());
+    }
+
+    if chars.get(i) == Some(&'#') {
+        out.alt = true;
+        i += 1;
+    }
+
+    if chars.get(i) == Some(&'0') {
+        out.zero = true;
+        i += 1;
+    }
+
+    let digits = |i: &mut usize| {
+        let start = *i;
+        while chars.get(*i).is_some_and(char::is_ascii_digit) {
+            *i += 1;
+        }
+        chars[start..*i].iter().collect::<String>().parse().ok()
+    };
+
+    out.width = digits(&mut i);
+    if chars.get(i) == Some(&'$') {
+        return Err("widths taken from arguments are unsupported".into());
+    }
+
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+        out.precision = digits(&mut i);
+        if out.precision.is_none() || chars.get(i) == Some(&'$') {
+            return Err("precisions taken from arguments are unsupported".into());
+        }
+    }
+
+    out.ty = chars[i..].iter().collect();
+    Ok(out)
+}
+
+/// Builds the style for a format spec, given whether or not the argument is a
+/// number.
+fn style(
+    krate: &TokenTree,
+    spec: &Spec,
+    numeric: bool,
+) -> core::result::Result<TokenStream2, String> {
+    let fmt = quote!(#krate::fmt);
+    let upper = spec
+        .ty
+        .chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_uppercase());
+
+    let mut style = match (spec.ty.as_str(), spec.precision) {
+        ("", None) => quote!(#fmt::Display),
+        ("", Some(p)) if numeric => quote!(#fmt::Fixed::new(#p)),
+        ("", Some(p)) => quote!(#fmt::Truncate::new(#p, #fmt::Display)),
+        // like std, a precision only applies to numbers here
+        ("?", Some(p)) if numeric => quote!(#fmt::Fixed::new(#p)),
+        ("?", _) if spec.alt => quote!(#fmt::Pretty::new()),
+        ("?", _) => quote!(#fmt::Debug),
+        // like std, negative numbers are written in two's complement
+        ("x" | "X", None) => quote!(#fmt::Hex(#upper).twos_complement()),
+        ("b", None) => quote!(#fmt::Binary.twos_complement()),
+        ("o", None) => quote!(#fmt::Octal.twos_complement()),
+        ("e" | "E", None) => quote!(#fmt::Sci::new(#upper)),
+        ("e" | "E", Some(p)) => quote!(#fmt::Sci::precision(#p, #upper)),
+        ("x" | "X" | "b" | "o", Some(_)) => {
+            return Err(format!("a precision is unsupported for `{}`", spec.ty))
+        }
+        (ty, _) => return Err(format!("unknown format trait `{ty}`")),
+    };
+
+    let prefix = match spec.ty.as_str() {
+        "x" | "X" if spec.alt => Some("0x"),
+        "b" if spec.alt => Some("0b"),
+        "o" if spec.alt => Some("0o"),
+        _ => None,
+    };
+
+    if spec.plus {
+        style = quote!(#fmt::Sign(#style));
+    }
+
+    let width = spec.width.unwrap_or(0);
+    if spec.zero {
+        let count = width.saturating_sub(prefix.map_or(0, str::len));
+        style = quote!(#fmt::Pad {
+            align: #fmt::Dir::Sign,
+            with: '0',
+            count: #count,
+            kind: #fmt::Kind::Full,
+            measure: #fmt::Measure::Chars,
+            style: #style,
+        });
+    }
+
+    if let Some(prefix) = prefix {
+        style = quote!(#fmt::Prefix(#prefix, #style));
+    }
+
+    if !spec.zero && spec.width.is_some() {
+        let align = match spec.align {
+            Some('<') => quote!(Left),
+            Some('^') => quote!(Center),
+            Some(_) => quote!(Right),
+            None if numeric => quote!(Right),
+            None => quote!(Left),
+        };
+        let with = spec.fill.unwrap_or(' ');
+
+        style = quote!(#fmt::Pad {
+            align: #fmt::Dir::#align,
+            with: #with,
+            count: #width,
+            kind: #fmt::Kind::Full,
+            measure: #fmt::Measure::Chars,
+            style: #style,
+        });
+    }
+
+    Ok(style)
+}
@@ -0,0 +1,312 @@
+//! Derive macros for the formatting styles of
+//! [`restd`](https://crates.io/crates/restd).
+//!
+//! These are re-exported from `restd::fmt` by its `derive` feature, and
+//! shouldn't be depended upon directly. The same feature enables std-style
+//! format strings in `restd`'s formatting macros, which are parsed here.
+//!
+//! Both derives accept the following attributes:
+//! - `#[restd(skip)]` on a field omits it from the output.
+//! - `#[restd(style = <expr>)]` on a field formats it using the given style
+//!   instead, e.g. `#[restd(style = Hex::prefix(false))]`.
+//! - `#[restd(non_exhaustive)]` on a struct or enum variant adds a trailing
+//!   `...` to the output.
+//! - `#[restd(bound = "<predicates>")]` on a struct or enum adds extra
+//!   predicates to the generated impl's where clause.
+//!
+//! By default, every type parameter used by an unstyled field must implement
+//! the derived style.
+
+mod format;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Error,
+    Expr, Fields, Ident, Index, LitStr, Result, Token, Type, WherePredicate,
+};
+
+/// Derive `Format<Debug>`, mimicking the Rust structure of the type.
+#[proc_macro_derive(Debug, attributes(restd))]
+pub fn derive_debug(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "Debug")
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `Format<Pretty>`, mimicking the Rust structure of the type.
+#[proc_macro_derive(Pretty, attributes(restd))]
+pub fn derive_pretty(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "Pretty")
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implementation detail of `restd::format_args!(std "...")` and friends.
+///
+/// Takes the path to `restd`, whether to add a trailing newline, a std-style
+/// format string, and its arguments.
+#[doc(hidden)]
+#[proc_macro]
+pub fn std_format_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as format::Input);
+    format::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The options given via `#[restd(...)]` attributes.
+#[derive(Default)]
+struct Attrs {
+    skip: bool,
+    style: Option<Expr>,
+    non_exhaustive: bool,
+    bound: Vec<WherePredicate>,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut out = Self::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("restd")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else if meta.path.is_ident("style") {
+                    out.style = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("non_exhaustive") {
+                    out.non_exhaustive = true;
+                } else if meta.path.is_ident("bound") {
+                    let bound: LitStr = meta.value()?.parse()?;
+                    out.bound
+                        .extend(bound.parse_with(Punctuated::<_, Token![,]>::parse_terminated)?);
+                } else {
+                    return Err(meta.error("unknown `restd` attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(out)
+    }
+
+    /// Parses attributes for a field.
+    fn field(attrs: &[Attribute]) -> Result<Self> {
+        let out = Self::parse(attrs)?;
+        if out.non_exhaustive || !out.bound.is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "`non_exhaustive` and `bound` aren't allowed on fields",
+            ));
+        }
+
+        Ok(out)
+    }
+
+    /// Parses attributes for a struct, enum, or enum variant.
+    fn container(attrs: &[Attribute], variant: bool) -> Result<Self> {
+        let out = Self::parse(attrs)?;
+        if out.skip || out.style.is_some() {
+            return Err(Error::new(
+                Span::call_site(),
+                "`skip` and `style` are only allowed on fields",
+            ));
+        } else if variant && !out.bound.is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "`bound` isn't allowed on enum variants",
+            ));
+        }
+
+        Ok(out)
+    }
+}
+
+fn expand(input: &DeriveInput, style: &str) -> Result<TokenStream2> {
+    let style = Ident::new(style, Span::call_site());
+    let attrs = Attrs::container(&input.attrs, false)?;
+    let name = &input.ident;
+
+    // the types of every field formatted in the derived style
+    let mut used = Vec::new();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let access = data.fields.iter().enumerate().map(|(i, field)| {
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let i = Index::from(i);
+                        quote!(#i)
+                    }
+                };
+                quote!(&self.#member)
+            });
+
+            let access = access.collect::<Vec<_>>();
+            build(
+                &name.unraw(),
+                &data.fields,
+                &access,
+                attrs.non_exhaustive,
+                &mut used,
+            )?
+        }
+
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let vattrs = Attrs::container(&variant.attrs, true)?;
+                    let vname = &variant.ident;
+
+                    let mut access = Vec::new();
+                    let mut bindings = Vec::new();
+                    for (i, field) in variant.fields.iter().enumerate() {
+                        let binding = match &field.ident {
+                            Some(ident) => format_ident!("__self_{}", ident.unraw()),
+                            None => format_ident!("__self_{}", i),
+                        };
+
+                        access.push(quote!(#binding));
+                        bindings.push(match &field.ident {
+                            Some(ident) => quote!(#ident: #binding),
+                            None => quote!(#binding),
+                        });
+                    }
+
+                    let pattern = match &variant.fields {
+                        Fields::Named(_) => quote!(Self::#vname { #(#bindings,)* }),
+                        Fields::Unnamed(_) => quote!(Self::#vname(#(#bindings,)*)),
+                        Fields::Unit => quote!(Self::#vname),
+                    };
+
+                    let body = build(
+                        &vname.unraw(),
+                        &variant.fields,
+                        &access,
+                        vattrs.non_exhaustive,
+                        &mut used,
+                    )?;
+
+                    Ok(quote!(#pattern => { #body }))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            quote! {
+                #[allow(unused_variables)]
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`restd` can't derive styles for unions",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for param in input.generics.type_params() {
+        let ident = &param.ident;
+        if used.iter().any(|ty| mentions(quote!(#ty), ident)) {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ident: ::restd::fmt::Format<::restd::fmt::#style>));
+        }
+    }
+    where_clause.predicates.extend(attrs.bound);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::restd::fmt::Format<::restd::fmt::#style> for #name #ty_generics
+        #where_clause
+        {
+            #[allow(unused_variables)]
+            fn fmt(
+                &self,
+                f: &mut dyn ::restd::fmt::Write,
+                s: &::restd::fmt::#style,
+            ) -> ::restd::fmt::Result {
+                let s = &s.group(self);
+                #body
+            }
+        }
+    })
+}
+
+/// Generates the calls to format a struct or variant, given an expression
+/// referencing each field.
+fn build(
+    name: &Ident,
+    fields: &Fields,
+    access: &[TokenStream2],
+    non_exhaustive: bool,
+    used: &mut Vec<Type>,
+) -> Result<TokenStream2> {
+    let name = name.to_string();
+
+    let builder = match fields {
+        Fields::Named(_) => quote!(dbg_struct),
+        Fields::Unnamed(_) => quote!(dbg_tuple),
+        Fields::Unit if non_exhaustive => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`non_exhaustive` requires named or unnamed fields",
+            ))
+        }
+        Fields::Unit => return Ok(quote!(::restd::fmt::Write::write_str(f, #name))),
+    };
+
+    let mut calls = Vec::new();
+    for (field, access) in fields.iter().zip(access) {
+        let attrs = Attrs::field(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let label = field.ident.as_ref().map(|ident| {
+            let ident = ident.unraw().to_string();
+            quote!(#ident,)
+        });
+
+        calls.push(match attrs.style {
+            Some(style) => quote!(f.field_styled(#label #access, &(#style));),
+            None => {
+                used.push(field.ty.clone());
+                quote!(f.field(#label #access);)
+            }
+        });
+    }
+
+    let finish = if non_exhaustive {
+        quote!(f.non_exhaustive())
+    } else {
+        quote!(f.finish())
+    };
+
+    Ok(quote! {
+        let mut f = s.#builder(f, #name);
+        #(#calls)*
+        #finish
+    })
+}
+
+/// Returns whether or not `tokens` contains `ident`.
+fn mentions(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => &i == ident,
+        TokenTree::Group(g) => mentions(g.stream(), ident),
+        _ => false,
+    })
+}
@@ -0,0 +1,139 @@
+use restd::fmt::{AnyStyle, Debug, Display, Format, FormatAny, Formats, Hex, Pad, Prefix, Pretty};
+
+#[derive(Debug, Pretty)]
+struct Unit;
+
+#[derive(Debug, Pretty)]
+#[allow(dead_code)]
+struct Tuple(u32, #[restd(skip)] bool, &'static str);
+
+#[derive(Debug, Pretty)]
+#[restd(non_exhaustive)]
+struct Named {
+    x: u32,
+    #[restd(style = Hex::prefix(false))]
+    y: u32,
+    #[restd(skip)]
+    #[allow(dead_code)]
+    z: bool,
+}
+
+#[derive(Debug, Pretty)]
+enum Enum<T> {
+    A(#[restd(style = Hex(false))] u32),
+    B,
+    #[restd(non_exhaustive)]
+    C {
+        x: T,
+        #[restd(skip)]
+        #[allow(dead_code)]
+        y: (),
+    },
+    D {
+        f: u8,
+        r#type: u8,
+    },
+}
+
+// `Q` is only ever formatted with `Display`, so it doesn't need to be `Debug`
+#[derive(Debug, Pretty)]
+#[restd(bound = "Q: Format<Display>")]
+struct Generic<T, Q> {
+    x: T,
+    #[restd(style = Display)]
+    y: Q,
+}
+
+restd::fmt::impl_any! {
+    [Unit, Named] => [Debug, Pretty];
+    <T> [Enum<T>] => [Debug, Pretty];
+}
+
+#[test]
+fn structs() {
+    assert_eq!(Unit.stringify(&Debug), "Unit");
+    assert_eq!(
+        Tuple(1, true, "foo").stringify(&Debug),
+        r#"Tuple(1, "foo")"#
+    );
+
+    let named = Named {
+        x: 123,
+        y: 0xabc,
+        z: false,
+    };
+    assert_eq!(named.stringify(&Debug), "Named { x: 123, y: 0xabc, ... }");
+    assert_eq!(
+        named.stringify(&Pretty::new()),
+        "Named {\n    x: 123,\n    y: 0xabc,\n    ...\n}"
+    );
+    assert_eq!(
+        named.stringify(&Pretty::new().width(80)),
+        "Named { x: 123, y: 0xabc, ... }"
+    );
+}
+
+#[test]
+fn enums() {
+    assert_eq!(Enum::<u8>::A(0x1a2b).stringify(&Debug), "A(1a2b)");
+    assert_eq!(Enum::<u8>::B.stringify(&Debug), "B");
+    assert_eq!(
+        Enum::C { x: 12.5, y: () }.stringify(&Debug),
+        "C { x: 12.5, ... }"
+    );
+    assert_eq!(
+        Enum::<u8>::D { f: 1, r#type: 2 }.stringify(&Pretty::new()),
+        "D {\n    f: 1,\n    type: 2,\n}"
+    );
+}
+
+#[test]
+fn generics() {
+    struct NotDebug;
+    impl Format<Display> for NotDebug {
+        fn fmt(&self, f: &mut dyn restd::fmt::Write, _: &Display) -> restd::fmt::Result {
+            f.write_str("not debug")
+        }
+    }
+
+    let x = Generic {
+        x: [1, 2],
+        y: NotDebug,
+    };
+    assert_eq!(x.stringify(&Debug), "Generic { x: [1, 2], y: not debug }");
+    assert_eq!(
+        x.stringify(&Pretty::new().width(80)),
+        "Generic { x: [1, 2], y: not debug }"
+    );
+}
+
+#[test]
+fn modifiers() {
+    // no `stylable!` needed for modifiers to apply
+    let mut f = String::new();
+    Pad::right('.', 10, Prefix(">", Debug))
+        .format(&mut f, &Enum::<u8>::A(0xff))
+        .unwrap();
+    Pad::left('-', 8, Debug).format(&mut f, &Unit).unwrap();
+    assert_eq!(f, "....>A(ff)Unit----");
+}
+
+#[test]
+fn any_style() {
+    let style = AnyStyle::from_name("pretty").unwrap();
+    let named = Named {
+        x: 1,
+        y: 2,
+        z: false,
+    };
+    assert_eq!(
+        named.stringify(&style),
+        "Named {\n    x: 1,\n    y: 0x2,\n    ...\n}"
+    );
+
+    // anything else falls back to the first style listed
+    let style = AnyStyle::from_name("hex").unwrap();
+    let values: [&dyn FormatAny; 2] = [&Unit, &Enum::<u8>::A(0xff)];
+    let f = values.map(|v| (v.supports(&style), v.stringify(&style)));
+    assert_eq!(f, [(false, "Unit".into()), (false, "A(ff)".into())]);
+}
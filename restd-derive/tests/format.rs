@@ -0,0 +1,123 @@
+use restd::fmt::Write;
+use restd::format;
+
+#[test]
+fn basic() {
+    let name = "restd";
+    let x = 255;
+
+    assert_eq!(format!(std "hello"), "hello");
+    assert_eq!(format!(std "{{{}}}", 1), "{1}");
+    assert_eq!(format!(std "{} {0} {name}", x), "255 255 restd");
+    assert_eq!(format!(std "{a}-{b}", a = 1, b = 'b',), "1-b");
+    assert_eq!(format!(std "{:?} {name:?}", "q"), r#""q" "restd""#);
+    assert_eq!(format!(std "{:#?}", (1,)), "(\n    1,\n)");
+}
+
+#[test]
+fn specs() {
+    let x = 255;
+    let cases = [
+        (
+            format!(std "{x:x} {x:X} {x:#x} {x:b} {x:#o}"),
+            "ff FF 0xff 11111111 0o377",
+        ),
+        (
+            format!(std "[{x:>6}] [{x:<6}] [{x:^6}] [{x:*^7}]"),
+            "[   255] [255   ] [ 255  ] [**255**]",
+        ),
+        (format!(std "[{:6}] [{:6x}]", "ab", x), "[ab    ] [    ff]"),
+        (
+            format!(std "{:08} {:+05} {:#010x}", -5, 7, x),
+            "-0000005 +0007 0x000000ff",
+        ),
+        (
+            format!(std "{:.2} {:8.3} {:e} {:.1E}", 1.005, 2.5, 1500, 1234.5),
+            "1.00    2.500 1.5e3 1.2E3",
+        ),
+        (format!(std "{:+} {:+.1}", 1, -0.25), "+1 -0.2"),
+    ];
+
+    for (got, expected) in cases {
+        assert_eq!(got, expected);
+    }
+}
+
+#[test]
+fn like_std() {
+    let (a, b) = (-5i32, -1i8);
+    let cases = [
+        // negative numbers are written in two's complement
+        (
+            format!(std "{a:x} {a:X} {a:o} {a:b} {a:#x}"),
+            std::format!("{a:x} {a:X} {a:o} {a:b} {a:#x}"),
+        ),
+        (
+            format!(std "{b:x} {b:#o} {b:b} {b:#06x}"),
+            std::format!("{b:x} {b:#o} {b:b} {b:#06x}"),
+        ),
+        // and a precision on debug only applies to floats
+        (
+            format!(std "{:.2?} [{:8.3?}] {:.1?}", 1.005, -2.5, 'c'),
+            std::format!("{:.2?} [{:8.3?}] {:.1?}", 1.005, -2.5, 'c'),
+        ),
+    ];
+
+    for (got, expected) in cases {
+        assert_eq!(got, expected);
+    }
+}
+
+#[test]
+fn by_type() {
+    let n = 7;
+    let cases = [
+        // numbers align right by default, and anything else left
+        (
+            format!(std "[{:5}] [{:5?}] [{:5}] [{:5}] [{:4}]", 42, -1.5, "ab", 'c', &n),
+            std::format!(
+                "[{:5}] [{:5?}] [{:5}] [{:5}] [{:4}]",
+                42,
+                -1.5,
+                "ab",
+                'c',
+                &n
+            ),
+        ),
+        (
+            format!(std "[{:^7}] [{:>4}] [{:<4}]", "mid", "q", 1),
+            std::format!("[{:^7}] [{:>4}] [{:<4}]", "mid", "q", 1),
+        ),
+        // a precision truncates anything but numbers
+        (
+            format!(std "[{:.3}] [{:6.2}] [{:.8}] [{:6.1}]", "hello", "hello", "hi", 2.25),
+            std::format!(
+                "[{:.3}] [{:6.2}] [{:.8}] [{:6.1}]",
+                "hello",
+                "hello",
+                "hi",
+                2.25
+            ),
+        ),
+    ];
+
+    for (got, expected) in cases {
+        assert_eq!(got, expected);
+    }
+}
+
+#[test]
+fn evaluates_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+
+    assert_eq!(format!(std "{0} {0:?} {0:x}", next()), "1 1 1");
+    assert_eq!(calls, 1);
+
+    let mut s = String::new();
+    restd::writeln!(s, std "{}{}", 'a', "b").unwrap();
+    assert_eq!(s, "ab\n");
+}
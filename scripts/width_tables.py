@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+"""Generates `src/fmt/width/tables.rs` from Python's copy of the Unicode
+Character Database (`unicodedata`).
+
+Usage: python3 scripts/width_tables.py > src/fmt/width/tables.rs
+"""
+
+import unicodedata
+
+ZWJ = 0x200D
+
+
+def class_of(cp):
+    """Returns the class of a codepoint, or None for ordinary narrow ones."""
+    ch = chr(cp)
+    cat = unicodedata.category(ch)
+
+    # grapheme extenders: these join the previous cluster
+    if cat in ("Mn", "Me", "Mc") or cp in (0x200C, ZWJ):
+        return "Extend"
+    if 0x1F3FB <= cp <= 0x1F3FF or 0xE0020 <= cp <= 0xE007F:
+        return "Extend"
+
+    # conjoining hangul vowels and finals, which join the leading consonant
+    # before them into one syllable, taking up no columns of their own
+    if 0x1160 <= cp <= 0x11FF or (0xD7B0 <= cp <= 0xD7FF and cat != "Cn"):
+        return "Extend"
+
+    if 0x1F1E6 <= cp <= 0x1F1FF:
+        return "Regional"
+
+    # controls and formatting characters
+    if cat in ("Cc", "Cf", "Zl", "Zp") or cp == 0x200B:
+        return "Zero"
+
+    # `unicodedata` doesn't give unassigned characters their default widths,
+    # which are only wide in the blocks reserved for CJK ideographs
+    if cat == "Cn":
+        if 0x3400 <= cp <= 0x4DBF or 0x20000 <= cp <= 0x2FFFD or 0x30000 <= cp <= 0x3FFFD:
+            return "Wide"
+        return None
+
+    if unicodedata.east_asian_width(ch) in ("W", "F"):
+        return "Wide"
+
+    return None
+
+
+def ranges():
+    start, prev = None, None
+    for cp in range(0x110000):
+        cls = class_of(cp)
+        if prev is not None and (cls != prev[1] or cp != prev[0] + 1):
+            yield start, prev[0], prev[1]
+            start = None
+
+        if cls is None:
+            prev = None
+            continue
+
+        if start is None:
+            start = cp
+        prev = (cp, cls)
+
+    if prev is not None:
+        yield start, prev[0], prev[1]
+
+
+def main():
+    print("// Generated by `scripts/width_tables.py` from Unicode", end=" ")
+    print(f"{unicodedata.unidata_version}; do not edit.")
+    print()
+    print("use super::Class::{self, *};")
+    print()
+    print("/// Inclusive ranges of characters and their classes, sorted.")
+    print("/// Characters not listed are one column wide.")
+    print("#[rustfmt::skip]")
+    print("pub(super) static CLASSES: &[(char, char, Class)] = &[")
+    for lo, hi, cls in ranges():
+        print(f"    ('\\u{{{lo:x}}}', '\\u{{{hi:x}}}', {cls}),")
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
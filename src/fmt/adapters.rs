@@ -1,4 +1,7 @@
-use super::{Debug, Display, Error, Format, Pretty, Result, Write};
+use super::{
+    Debug, Dir, Display, DynFormat, Error, Format, Formats, Kind, Measure, Pad, Pretty, Result,
+    Styled, Truncate, Write,
+};
 use core::fmt;
 
 /// A wrapper around a type implementing [`core::fmt::Debug`] to make it
@@ -60,3 +63,67 @@ impl<T: Write> fmt::Write for RestdWrite<T> {
         Write::write_char(&mut self.0, ch).map_err(|_| fmt::Error)
     }
 }
+
+/// Formats the data in its restd style, so that it can be used with anything
+/// expecting [`core::fmt::Display`]. Like [`Formatter::pad`](fmt::Formatter::pad),
+/// a width pads the output (on the left by default), and a precision
+/// truncates it. Other flags, like `+` and `#`, are ignored.
+impl<T, S: Formats<T>> fmt::Display for Styled<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, precision) = (f.width(), f.precision());
+        let result = if width.is_none() && precision.is_none() {
+            self.style.format(&mut StdWrite(&mut *f), &self.data)
+        } else {
+            let align = match f.align() {
+                Some(fmt::Alignment::Right) => Dir::Right,
+                Some(fmt::Alignment::Center) => Dir::Center,
+                _ => Dir::Left,
+            };
+
+            Pad {
+                align,
+                with: f.fill(),
+                count: width.unwrap_or(0),
+                kind: Kind::Full,
+                measure: Measure::Chars,
+                style: Truncate {
+                    max: precision.unwrap_or(usize::MAX),
+                    ellipsis: "",
+                    measure: Measure::Chars,
+                    style: Display,
+                },
+            }
+            .format(&mut StdWrite(&mut *f), &Unpadded(self))
+        };
+
+        result.map_err(|_| fmt::Error)
+    }
+}
+
+/// A [`Styled`], formatted with its own style, for padding and truncating.
+struct Unpadded<'a, T, S>(&'a Styled<T, S>);
+
+impl<T, S: Formats<T>> Format<Display> for Unpadded<'_, T, S> {
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        self.0.style.format(f, &self.0.data)
+    }
+}
+
+/// Same as the `Display` impl: `{:?}` still uses the paired restd style.
+impl<T, S: Formats<T>> fmt::Debug for Styled<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for dyn DynFormat + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DynFormat::fmt(self, &mut StdWrite(f)).map_err(|_| fmt::Error)
+    }
+}
+
+impl fmt::Debug for dyn DynFormat + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
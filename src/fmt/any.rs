@@ -0,0 +1,190 @@
+use core::time::Duration;
+
+use super::{
+    args::Var, Base, Binary, Bytes, Debug, Display, Fixed, Format, Hex, HumanDuration, Octal,
+    Pretty, Result, Sci, Style, Write,
+};
+
+/// A style chosen at runtime, e.g. from a command-line flag.
+///
+/// Types that don't support the chosen style fall back to their usual one,
+/// e.g. [`Display`] for strings, so any mix of values can be formatted with
+/// one `AnyStyle`.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub enum AnyStyle {
+    Display(Display),
+    Debug(Debug),
+    Pretty(Pretty),
+    Hex(Hex),
+    Binary(Binary),
+    Octal(Octal),
+    Fixed(Fixed),
+    Sci(Sci),
+    Bytes(Bytes),
+    HumanDuration(HumanDuration),
+}
+
+impl Style for AnyStyle {
+    type Role = Base;
+
+    fn radix(&self) -> u32 {
+        match self {
+            Self::Hex(s) => s.radix(),
+            Self::Binary(s) => s.radix(),
+            Self::Octal(s) => s.radix(),
+            _ => 10,
+        }
+    }
+}
+
+impl AnyStyle {
+    pub(super) const NAMES: &'static [(&'static str, AnyStyle)] = &[
+        ("display", AnyStyle::Display(Display)),
+        ("debug", AnyStyle::Debug(Debug)),
+        ("pretty", AnyStyle::Pretty(Pretty::new())),
+        ("hex", AnyStyle::Hex(Hex(false))),
+        ("HEX", AnyStyle::Hex(Hex(true))),
+        ("bin", AnyStyle::Binary(Binary)),
+        ("oct", AnyStyle::Octal(Octal)),
+        ("sci", AnyStyle::Sci(Sci::new(false))),
+        ("SCI", AnyStyle::Sci(Sci::new(true))),
+        ("bytes", AnyStyle::Bytes(Bytes::decimal())),
+        ("ibytes", AnyStyle::Bytes(Bytes::binary())),
+        ("duration", AnyStyle::HumanDuration(HumanDuration)),
+    ];
+
+    /// Look up a style by name: one of `display`, `debug`, `pretty`, `hex`,
+    /// `HEX`, `bin`, `oct`, `sci`, `SCI`, `bytes`, `ibytes`, or `duration`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, style)| *style)
+    }
+}
+
+/// A type which can be formatted with an [`AnyStyle`].
+///
+/// Implemented for all primitives, strings, [`Duration`], slices, [`Option`],
+/// `Vec`, and [`Var`] (which only supports [`Display`], formatting with its
+/// own style). Implement it for other types with [`impl_any`].
+pub trait FormatAny: Format<AnyStyle> {
+    /// Whether or not `style` is supported, rather than falling back to the
+    /// usual style.
+    fn supports(&self, style: &AnyStyle) -> bool;
+}
+
+impl<T: FormatAny + ?Sized> FormatAny for &T {
+    fn supports(&self, style: &AnyStyle) -> bool {
+        (**self).supports(style)
+    }
+}
+
+impl Format<AnyStyle> for Var<'_> {
+    fn fmt(&self, f: &mut dyn Write, _: &AnyStyle) -> Result {
+        self.call(f)
+    }
+}
+
+impl FormatAny for Var<'_> {
+    fn supports(&self, style: &AnyStyle) -> bool {
+        matches!(style, AnyStyle::Display(_))
+    }
+}
+
+/// Implement [`FormatAny`] for types, given the [`AnyStyle`]s they support.
+/// The first style listed is the fallback for any other.
+///
+/// Generic parameters are listed before the types, and each type is bound on
+/// supporting every style listed.
+///
+/// ```
+/// use restd::fmt::{impl_any, AnyStyle, Debug, Format, Pretty};
+///
+/// struct Point(i32, i32);
+/// restd::fmt::derive!(struct Point(x, y));
+///
+/// struct Pair<T>(T, T);
+/// restd::fmt::derive!(struct Pair<T!>(a, b));
+///
+/// impl_any! {
+///     [Point] => [Debug, Pretty];
+///     <T> [Pair<T>] => [Debug, Pretty];
+/// }
+///
+/// let style = AnyStyle::from_name("hex").unwrap();
+/// assert_eq!(Pair(Point(1, 2), Point(3, 4)).stringify(&style), "Pair(Point(1, 2), Point(3, 4))");
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _impl_any {
+    (@each $g:tt $styles:tt $($t:ty),+) => {$(
+        $crate::_impl_any!(@impl $g $styles $t);
+    )+};
+
+    (@impl [$($g:ident),*] [$first:ident $(, $style:ident)*] $t:ty) => {
+        impl<$($g),*> $crate::fmt::Format<$crate::fmt::AnyStyle> for $t
+        where
+            $t: $crate::fmt::Format<$crate::fmt::$first>
+                $(+ $crate::fmt::Format<$crate::fmt::$style>)*,
+        {
+            fn fmt(
+                &self,
+                f: &mut dyn $crate::fmt::Write,
+                style: &$crate::fmt::AnyStyle,
+            ) -> $crate::fmt::Result {
+                match style {
+                    $crate::fmt::AnyStyle::$first(s) => {
+                        <$t as $crate::fmt::Format<$crate::fmt::$first>>::fmt(self, f, s)
+                    }
+                    $( $crate::fmt::AnyStyle::$style(s) => {
+                        <$t as $crate::fmt::Format<$crate::fmt::$style>>::fmt(self, f, s)
+                    } )*
+                    #[allow(unreachable_patterns)]
+                    _ => <$t as $crate::fmt::Format<$crate::fmt::$first>>::fmt(
+                        self,
+                        f,
+                        &$crate::fmt::$first::default(),
+                    ),
+                }
+            }
+        }
+
+        impl<$($g),*> $crate::fmt::FormatAny for $t
+        where
+            $t: $crate::fmt::Format<$crate::fmt::$first>
+                $(+ $crate::fmt::Format<$crate::fmt::$style>)*,
+        {
+            fn supports(&self, style: &$crate::fmt::AnyStyle) -> bool {
+                matches!(
+                    style,
+                    $crate::fmt::AnyStyle::$first(_) $( | $crate::fmt::AnyStyle::$style(_) )*
+                )
+            }
+        }
+    };
+
+    ($(
+        $(<$($g:ident),+ $(,)?>)? [$($t:ty),+ $(,)?] => [$first:ident $(, $style:ident)* $(,)?];
+    )*) => {$(
+        $crate::_impl_any!(@each [$($($g),+)?] [$first $(, $style)*] $($t),+);
+    )*};
+}
+pub use _impl_any as impl_any;
+
+impl_any! {
+    [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize] =>
+        [Display, Debug, Pretty, Hex, Binary, Octal, Fixed, Sci, Bytes];
+    [f32, f64] => [Display, Debug, Pretty, Fixed, Sci];
+    [str, char] => [Display, Debug, Pretty];
+    [bool] => [Debug, Pretty];
+    [Duration] => [HumanDuration];
+    <T> [[T], Option<T>] => [Debug, Pretty];
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl_any! {
+    [alloc::string::String] => [Display, Debug, Pretty];
+    <T> [alloc::vec::Vec<T>] => [Debug, Pretty];
+}
@@ -2,16 +2,16 @@
 
 use core::marker::PhantomData;
 
-use super::{Display, Format, Result, Style, Write};
+use super::{Display, Format, Formats, Result, Style, Write};
 
 /// A list of [`Var`]s.
 pub struct Arguments<'a>(pub &'a [Var<'a>]);
 
-type FmtFn<T, S> = fn(&T, &mut dyn Write, &S) -> Result;
+type FmtFn<T, S> = fn(&S, &mut dyn Write, &T) -> Result;
 type DynFmtFn = unsafe fn(
-    *const (), // self
-    &mut dyn Write,
     *const (), // style
+    &mut dyn Write,
+    *const (), // data
 ) -> Result;
 
 impl Arguments<'_> {
@@ -25,13 +25,38 @@ impl Arguments<'_> {
     }
 }
 
-crate::stylable!(for('a) Arguments<'a>);
 impl Format<Display> for Arguments<'_> {
     fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
         self.write(f)
     }
 }
 
+/// Values bound by name in [`format_args`](crate::format_args), along with
+/// a function to format the pieces referencing them.
+#[doc(hidden)]
+pub struct Bindings<B, F> {
+    binds: B,
+    func: F,
+}
+
+impl<B, F> Bindings<B, F>
+where
+    F: Fn(&B, &mut dyn Write) -> Result,
+{
+    pub fn new(binds: B, func: F) -> Self {
+        Self { binds, func }
+    }
+}
+
+impl<B, F> Format<Display> for Bindings<B, F>
+where
+    F: Fn(&B, &mut dyn Write) -> Result,
+{
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        (self.func)(&self.binds, f)
+    }
+}
+
 /// Essentially a vtable for a [`Format`]. Contains references to the data, the
 /// style, and the data's method for formatting with that style.
 pub struct Var<'a> {
@@ -48,8 +73,7 @@ impl<'a> Var<'a> {
     /// Format using [`call`](Var::call).
     pub fn new<T, S>(data: &'a T, style: &'a S) -> Var<'a>
     where
-        T: Format<S>,
-        S: Style,
+        S: Formats<T>,
     {
         Self {
             data: data as *const T as *const (),
@@ -57,7 +81,9 @@ impl<'a> Var<'a> {
 
             // SAFETY: `*const ()` is ABI-compatible with `&T where T: Sized`
             func: unsafe {
-                core::mem::transmute::<FmtFn<T, S>, DynFmtFn>(<T as Format<S>>::fmt as FmtFn<T, S>)
+                core::mem::transmute::<FmtFn<T, S>, DynFmtFn>(
+                    <S as Formats<T>>::format as FmtFn<T, S>,
+                )
             },
 
             _lt: PhantomData,
@@ -66,6 +92,69 @@ impl<'a> Var<'a> {
 
     /// Format the type using the data stored by [`new`](Var::new).
     pub fn call(&self, f: &mut dyn Write) -> Result {
-        unsafe { (self.func)(self.data, f, self.style) }
+        unsafe { (self.func)(self.style, f, self.data) }
     }
 }
+
+/// Picks between a pair of styles by the type of the data: the first for
+/// numbers, and the second for anything else. Used by std-style format strings,
+/// where e.g. the default alignment depends on the type.
+///
+/// Call [`var`](PickNumber::var) on a reference to it, with both
+/// [`PickNumber`] and [`PickOther`] in scope.
+#[doc(hidden)]
+pub struct Pick<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait PickNumber<'a, T> {
+    fn var<A: Formats<T>, B: Style>(&self, styles: &'a (A, B)) -> Var<'a>;
+}
+
+#[doc(hidden)]
+pub trait PickOther<'a, T> {
+    fn var<A: Style, B: Formats<T>>(&self, styles: &'a (A, B)) -> Var<'a>;
+}
+
+// taken by reference, so this is only used if `PickNumber` isn't implemented
+impl<'a, T> PickOther<'a, T> for &Pick<'a, T> {
+    fn var<A: Style, B: Formats<T>>(&self, styles: &'a (A, B)) -> Var<'a> {
+        Var::new(self.0, &styles.1)
+    }
+}
+
+impl<'a, 'b, T> PickNumber<'a, &'b T> for Pick<'a, &'b T>
+where
+    Pick<'b, T>: PickNumber<'b, T>,
+{
+    fn var<A: Formats<&'b T>, B: Style>(&self, styles: &'a (A, B)) -> Var<'a> {
+        Var::new(self.0, &styles.0)
+    }
+}
+
+/// A number, which can be formatted with a `+` flag in std-style format
+/// strings.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the `+` flag can only be used on numbers, not `{Self}`",
+    label = "not a number"
+)]
+pub trait Signed {}
+
+impl<T: Signed + ?Sized> Signed for &T {}
+
+/// Checks that `data` can be formatted with a `+` flag.
+#[doc(hidden)]
+pub fn signed<T: Signed + ?Sized>(_: &T) {}
+
+macro_rules! pick_number {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {}
+
+        impl<'a> PickNumber<'a, $t> for Pick<'a, $t> {
+            fn var<A: Formats<$t>, B: Style>(&self, styles: &'a (A, B)) -> Var<'a> {
+                Var::new(self.0, &styles.0)
+            }
+        }
+    )*};
+}
+pick_number![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64];
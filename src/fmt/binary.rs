@@ -2,13 +2,22 @@ use super::Prefix;
 
 /// A repr for formatting data in binary. Implemented for all integers.
 ///
+/// Negative numbers are written as a `-` followed by their magnitude; see
+/// [`Bits`](super::Bits) for two's complement and full-width output.
+///
 /// Unlike [`Debug`](super::Debug) and [`Pretty`](super::Pretty), cannot be
 /// derived, since most types cannot be intuitively formatted in binary.
 ///
 /// Equivalent of [`core::fmt::Binary`].
 #[derive(Default, Clone, Copy)]
 pub struct Binary;
-impl super::Style for Binary {}
+impl super::Style for Binary {
+    type Role = super::Base;
+
+    fn radix(&self) -> u32 {
+        2
+    }
+}
 super::derive!(struct Binary);
 
 impl Binary {
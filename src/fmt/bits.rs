@@ -0,0 +1,111 @@
+use super::{Base, Binary, Hex, Octal, Style};
+
+/// How [`Bits`] represents negative numbers.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signed {
+    /// A `-` followed by the magnitude, e.g. `-1_i8` in hex is `-1`.
+    ///
+    /// This is what [`Hex`], [`Octal`], and [`Binary`] do on their own.
+    #[default]
+    Magnitude,
+
+    /// The raw bits of the number, e.g. `-1_i8` in hex is `ff`.
+    TwosComplement,
+}
+super::derive!(
+    enum Signed {
+        Magnitude,
+        TwosComplement,
+    }
+);
+
+/// Controls how [`Hex`], [`Octal`], and [`Binary`] represent integers:
+/// whether negative numbers are written as a magnitude or in two's complement,
+/// and whether or not to zero-pad to the full width of the type (e.g. a `u16`
+/// is always 4 hex digits or 16 bits).
+///
+/// Usually created via methods on the styles themselves, e.g.
+/// `Hex(false).twos_complement().full_width()`.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Bits<S> {
+    /// The style being wrapped.
+    pub style: S,
+
+    /// How to represent negative numbers.
+    pub signed: Signed,
+
+    /// Whether or not to zero-pad to the full width of the type.
+    pub full: bool,
+}
+impl<S: Style> Style for Bits<S> {
+    type Role = Base;
+
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+super::derive!(struct Bits<S!> { style, signed, full });
+
+// `Copy` so these can be `const`, which every style `Bits` wraps is
+impl<S: Copy> Bits<S> {
+    /// Represent negative numbers as a `-` followed by their magnitude.
+    pub const fn magnitude(self) -> Self {
+        Self {
+            signed: Signed::Magnitude,
+            ..self
+        }
+    }
+
+    /// Represent negative numbers using their raw bits.
+    pub const fn twos_complement(self) -> Self {
+        Self {
+            signed: Signed::TwosComplement,
+            ..self
+        }
+    }
+
+    /// Zero-pad to the full width of the type.
+    pub const fn full_width(self) -> Self {
+        Self { full: true, ..self }
+    }
+}
+
+macro_rules! bits_ctors {
+    ($($style:ident),*) => {$(
+        impl $style {
+            /// Represent negative numbers as a `-` followed by their magnitude.
+            ///
+            /// Shorthand for `Bits { style, signed: Magnitude, full: false }`.
+            pub const fn magnitude(self) -> Bits<Self> {
+                Bits {
+                    style: self,
+                    signed: Signed::Magnitude,
+                    full: false,
+                }
+            }
+
+            /// Represent negative numbers using their raw bits.
+            ///
+            /// Shorthand for `Bits { style, signed: TwosComplement, full: false }`.
+            pub const fn twos_complement(self) -> Bits<Self> {
+                Bits {
+                    style: self,
+                    signed: Signed::TwosComplement,
+                    full: false,
+                }
+            }
+
+            /// Zero-pad to the full width of the type.
+            ///
+            /// Shorthand for `Bits { style, signed: Magnitude, full: true }`.
+            pub const fn full_width(self) -> Bits<Self> {
+                Bits {
+                    style: self,
+                    signed: Signed::Magnitude,
+                    full: true,
+                }
+            }
+        }
+    )*};
+}
+bits_ctors![Hex, Octal, Binary];
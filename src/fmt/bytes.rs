@@ -0,0 +1,27 @@
+/// A repr for formatting a number of bytes in human-readable units, e.g.
+/// `1.5 KiB` or `3 MB`. Implemented for all integers.
+///
+/// Values are rounded to one decimal place (ties to even), which is omitted if
+/// zero. Values below one kilobyte are written exactly, e.g. `512 B`.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes {
+    /// Whether to use binary units (`KiB`, `MiB`, ...; powers of 1024) instead
+    /// of decimal ones (`kB`, `MB`, ...; powers of 1000).
+    pub binary: bool,
+}
+impl super::Style for Bytes {
+    type Role = super::Base;
+}
+super::derive!(struct Bytes { binary });
+
+impl Bytes {
+    /// Create a new `Bytes` using binary units (`KiB`, `MiB`, ...).
+    pub const fn binary() -> Self {
+        Self { binary: true }
+    }
+
+    /// Create a new `Bytes` using decimal units (`kB`, `MB`, ...).
+    pub const fn decimal() -> Self {
+        Self { binary: false }
+    }
+}
@@ -0,0 +1,73 @@
+use super::{Combinator, Combined, Display, Format, Formats, Result, Style, Write};
+
+/// Formats the data with `A`, then again with `B`; e.g. `255` with
+/// `Then(Display, Hex::prefix(false))` is `2550xff`.
+///
+/// See [`Both`] to put a separator between the two.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Then<A, B>(pub A, pub B);
+super::derive!(struct Then<A!, B!>(a, b));
+
+impl<A: Style, B: Style> Style for Then<A, B> {
+    type Role = Combined;
+}
+
+impl<A: Style, B: Style> Combinator for Then<A, B> {
+    type First = A;
+    type Second = B;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        A: Formats<T>,
+        B: Formats<T>,
+    {
+        self.0.format(f, data)?;
+        self.1.format(f, data)
+    }
+}
+
+/// Formats the data with `first`, then `sep`, then the data again with
+/// `second`; e.g. `255` with `Both::new(Display, " = ", Hex::prefix(false))`
+/// is `255 = 0xff`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Both<A, P, B> {
+    /// The first style to format with.
+    pub first: A,
+
+    /// The separator to print between the two.
+    pub sep: P,
+
+    /// The second style to format with.
+    pub second: B,
+}
+super::derive!(struct Both<A!, P!, B!> { first, sep, second });
+
+impl<A: Style, P: Format<Display>, B: Style> Both<A, P, B> {
+    /// Format with `first` and `second`, separated by `sep`.
+    ///
+    /// Shorthand for `Both { first, sep, second }`.
+    pub const fn new(first: A, sep: P, second: B) -> Self {
+        Self { first, sep, second }
+    }
+}
+
+impl<A: Style, P: Format<Display>, B: Style> Style for Both<A, P, B> {
+    type Role = Combined;
+}
+
+impl<A: Style, P: Format<Display>, B: Style> Combinator for Both<A, P, B> {
+    type First = A;
+    type Second = B;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        A: Formats<T>,
+        B: Formats<T>,
+    {
+        self.first.format(f, data)?;
+        self.sep.fmt(f, &Display)?;
+        self.second.format(f, data)
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Format, Result, Style, Write};
+use super::{Base, Format, Formats, Result, Style, Write};
 
 /// A programmer-friendly repr for debugging purposes.
 ///
@@ -9,10 +9,19 @@ use super::{Format, Result, Style, Write};
 /// Equivalent of [`core::fmt::Debug`].
 #[derive(Default, Clone, Copy)]
 pub struct Debug;
-impl Style for Debug {}
+impl Style for Debug {
+    type Role = Base;
+}
 super::derive!(struct Debug);
 
 impl Debug {
+    /// Does nothing; exists for parity with
+    /// [`Pretty::group`](super::Pretty::group), so that code can be generic
+    /// over both.
+    pub fn group<T: ?Sized>(&self, _data: &T) -> Self {
+        *self
+    }
+
     /// Assists with formatting tuple structs (or tuples, when `name` is empty).
     pub fn dbg_tuple<'w>(&self, f: &'w mut dyn Write, name: &str) -> DebugTuple<'w> {
         DebugTuple::new(f, name)
@@ -22,6 +31,21 @@ impl Debug {
     pub fn dbg_struct<'w>(&self, f: &'w mut dyn Write, name: &str) -> DebugStruct<'w> {
         DebugStruct::new(f, name)
     }
+
+    /// Assists with formatting lists, e.g. `[1, 2, 3]`.
+    pub fn dbg_list<'w>(&self, f: &'w mut dyn Write) -> DebugList<'w> {
+        DebugList::new(f)
+    }
+
+    /// Assists with formatting sets, e.g. `{1, 2, 3}`.
+    pub fn dbg_set<'w>(&self, f: &'w mut dyn Write) -> DebugList<'w> {
+        DebugList::set(f)
+    }
+
+    /// Assists with formatting maps, e.g. `{"a": 1, "b": 2}`.
+    pub fn dbg_map<'w>(&self, f: &'w mut dyn Write) -> DebugMap<'w> {
+        DebugMap::new(f)
+    }
 }
 
 /// A helper for formatting tuples and tuple structs.
@@ -29,7 +53,8 @@ impl Debug {
 /// If an error is encountered, any future calls will no-op.
 pub struct DebugTuple<'w> {
     f: &'w mut dyn Write,
-    first: bool,
+    fields: usize,
+    tuple: bool,
     err: Result,
 }
 
@@ -40,7 +65,8 @@ impl<'w> DebugTuple<'w> {
 
         Self {
             f,
-            first: true,
+            fields: 0,
+            tuple: name.is_empty(),
             err,
         }
     }
@@ -51,32 +77,38 @@ impl<'w> DebugTuple<'w> {
     }
 
     /// Format a field using a given style.
-    pub fn field_styled<T: Format<S>, S: Style>(&mut self, data: &T, style: &S) -> &mut Self {
-        self.field_with(|f| data.fmt(f, style))
+    pub fn field_styled<T, S: Formats<T>>(&mut self, data: &T, style: &S) -> &mut Self {
+        self.field_with(|f| style.format(f, data))
     }
 
     /// Format a field using a given closure instead of data.
     pub fn field_with(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) -> &mut Self {
-        if !self.first {
+        if self.fields != 0 {
             self.with_err(|f| f.write_str(", "));
         }
 
         self.with_err(f);
-        self.first = false;
+        self.fields += 1;
 
         self
     }
 
     /// Finish off the tuple (struct), returning an error if any were
     /// encountered.
+    ///
+    /// Tuples with a single field get a trailing comma, e.g. `(1,)`.
     pub fn finish(&mut self) -> Result {
+        if self.tuple && self.fields == 1 {
+            self.with_err(|f| f.write_char(','));
+        }
+
         self.err.and_then(|_| self.f.write_char(')'))
     }
 
     /// Finish off the tuple (struct), with a trailing `...` field, returning an
     /// error if any were encountered.
     pub fn non_exhaustive(&mut self) -> Result {
-        if !self.first {
+        if self.fields != 0 {
             self.with_err(|f| f.write_str(", "));
         }
 
@@ -117,13 +149,8 @@ impl<'w> DebugStruct<'w> {
     }
 
     /// Format a field using a given style.
-    pub fn field_styled<T: Format<S>, S: Style>(
-        &mut self,
-        name: &str,
-        data: &T,
-        style: &S,
-    ) -> &mut Self {
-        self.field_with(name, |f| data.fmt(f, style))
+    pub fn field_styled<T, S: Formats<T>>(&mut self, name: &str, data: &T, style: &S) -> &mut Self {
+        self.field_with(name, |f| style.format(f, data))
     }
 
     /// Format a field using a given closure instead of data.
@@ -165,3 +192,173 @@ impl<'w> DebugStruct<'w> {
         self.err = self.err.and_then(|_| f(self.f));
     }
 }
+
+/// A helper for formatting lists and sets.
+///
+/// If an error is encountered, any future calls will no-op.
+pub struct DebugList<'w> {
+    f: &'w mut dyn Write,
+    first: bool,
+    close: char,
+    err: Result,
+}
+
+impl<'w> DebugList<'w> {
+    /// Create a new `DebugList`, surrounded by square brackets.
+    pub fn new(f: &'w mut dyn Write) -> Self {
+        Self::with_delims(f, '[', ']')
+    }
+
+    /// Create a new `DebugList` for a set, surrounded by curly braces.
+    pub fn set(f: &'w mut dyn Write) -> Self {
+        Self::with_delims(f, '{', '}')
+    }
+
+    fn with_delims(f: &'w mut dyn Write, open: char, close: char) -> Self {
+        let err = f.write_char(open);
+
+        Self {
+            f,
+            first: true,
+            close,
+            err,
+        }
+    }
+
+    /// Format an entry using [`Debug`].
+    pub fn entry<T: Format<Debug> + ?Sized>(&mut self, data: &T) -> &mut Self {
+        self.entry_with(|f| data.fmt(f, &Debug))
+    }
+
+    /// Format an entry using a given style.
+    pub fn entry_styled<T: ?Sized, S: Formats<T>>(&mut self, data: &T, style: &S) -> &mut Self {
+        self.entry_with(|f| style.format(f, data))
+    }
+
+    /// Format an entry using a given closure instead of data.
+    pub fn entry_with(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) -> &mut Self {
+        if !self.first {
+            self.with_err(|f| f.write_str(", "));
+        }
+
+        self.with_err(f);
+        self.first = false;
+
+        self
+    }
+
+    /// Format every item of an iterator as an entry using [`Debug`].
+    pub fn entries<T: Format<Debug>>(&mut self, iter: impl IntoIterator<Item = T>) -> &mut Self {
+        for data in iter {
+            self.entry(&data);
+        }
+
+        self
+    }
+
+    /// Finish off the list (or set), returning an error if any were
+    /// encountered.
+    pub fn finish(&mut self) -> Result {
+        self.err.and_then(|_| self.f.write_char(self.close))
+    }
+
+    /// Finish off the list (or set), with a trailing `...` entry, returning an
+    /// error if any were encountered.
+    pub fn non_exhaustive(&mut self) -> Result {
+        if !self.first {
+            self.with_err(|f| f.write_str(", "));
+        }
+
+        self.with_err(|f| f.write_str("..."));
+        self.err.and_then(|_| self.f.write_char(self.close))
+    }
+
+    /// Run a closure with the writer, if no errors have already been
+    /// encountered, updating `self.err`.
+    fn with_err(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) {
+        self.err = self.err.and_then(|_| f(self.f));
+    }
+}
+
+/// A helper for formatting maps.
+///
+/// If an error is encountered, any future calls will no-op.
+pub struct DebugMap<'w> {
+    f: &'w mut dyn Write,
+    first: bool,
+    err: Result,
+}
+
+impl<'w> DebugMap<'w> {
+    /// Create a new `DebugMap`.
+    pub fn new(f: &'w mut dyn Write) -> Self {
+        let err = f.write_char('{');
+
+        Self {
+            f,
+            first: true,
+            err,
+        }
+    }
+
+    /// Format an entry using [`Debug`].
+    pub fn entry<K, V>(&mut self, key: &K, value: &V) -> &mut Self
+    where
+        K: Format<Debug> + ?Sized,
+        V: Format<Debug> + ?Sized,
+    {
+        self.entry_with(|f| key.fmt(f, &Debug), |f| value.fmt(f, &Debug))
+    }
+
+    /// Format an entry using given closures instead of data.
+    pub fn entry_with(
+        &mut self,
+        key: impl FnOnce(&mut dyn Write) -> Result,
+        value: impl FnOnce(&mut dyn Write) -> Result,
+    ) -> &mut Self {
+        if !self.first {
+            self.with_err(|f| f.write_str(", "));
+        }
+
+        self.with_err(key);
+        self.with_err(|f| f.write_str(": "));
+        self.with_err(value);
+        self.first = false;
+
+        self
+    }
+
+    /// Format every key-value pair of an iterator as an entry using [`Debug`].
+    pub fn entries<K, V>(&mut self, iter: impl IntoIterator<Item = (K, V)>) -> &mut Self
+    where
+        K: Format<Debug>,
+        V: Format<Debug>,
+    {
+        for (key, value) in iter {
+            self.entry(&key, &value);
+        }
+
+        self
+    }
+
+    /// Finish off the map, returning an error if any were encountered.
+    pub fn finish(&mut self) -> Result {
+        self.err.and_then(|_| self.f.write_char('}'))
+    }
+
+    /// Finish off the map, with a trailing `...` entry, returning an error if
+    /// any were encountered.
+    pub fn non_exhaustive(&mut self) -> Result {
+        if !self.first {
+            self.with_err(|f| f.write_str(", "));
+        }
+
+        self.err.and_then(|_| self.f.write_str("...}"))
+    }
+
+    /// Run a closure with the writer, if no errors have already been
+    /// encountered, updating `self.err`.
+    fn with_err(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) {
+        self.err = self.err.and_then(|_| f(self.f));
+    }
+}
@@ -118,6 +118,7 @@ macro_rules! _do_derive {
                     $( $field )?
                 ),*) = self;
 
+                let s = &s.group(self);
                 let mut f = s.dbg_tuple(f, stringify!($name));
 
                 $($( $crate::_if_else!(
@@ -167,6 +168,7 @@ macro_rules! _do_derive {
                     $( $field )?
                 ),*) = self;
 
+                let s = &s.group(self);
                 let mut f = s.dbg_tuple(f, stringify!($name));
 
                 $($( $crate::_if_else!(
@@ -206,6 +208,7 @@ macro_rules! _do_derive {
                 f: &mut dyn $crate::fmt::Write,
                 s: &$crate::fmt::$style_name,
             ) -> $crate::fmt::Result {
+                let s = &s.group(self);
                 let mut f = s.dbg_struct(f, stringify!($name));
                 $( $crate::_if_else!(
                     [$( f.field_styled(
@@ -248,6 +251,7 @@ macro_rules! _do_derive {
                 f: &mut dyn $crate::fmt::Write,
                 s: &$crate::fmt::$style_name,
             ) -> $crate::fmt::Result {
+                let s = &s.group(self);
                 let mut f = s.dbg_struct(f, stringify!($name));
                 $( $crate::_if_else!(
                     [$( f.field_styled(
@@ -297,6 +301,7 @@ macro_rules! _do_derive {
                 f: &mut dyn $crate::fmt::Write,
                 s: &$crate::fmt::$style_name,
             ) -> $crate::fmt::Result {
+                let s = &s.group(self);
                 match self {$(
                     Self::$variant
                         $(( $(
@@ -6,5 +6,7 @@
 /// Equivalent of [`core::fmt::Display`].
 #[derive(Default, Clone, Copy)]
 pub struct Display;
-impl super::Style for Display {}
+impl super::Style for Display {
+    type Role = super::Base;
+}
 super::derive!(struct Display);
@@ -0,0 +1,16 @@
+/// A repr for formatting durations in a human-readable way. Implemented for
+/// [`Duration`](core::time::Duration).
+///
+/// Durations of a minute or more are written as their largest non-zero unit
+/// followed by the rest, down to seconds, e.g. `2m 03s`, `1h 02m 03s`, or
+/// `3d 00h 00m 05s`. Shorter durations use a single unit with up to one
+/// decimal place, e.g. `1.5s`, `250ms`, `12.3µs`, or `40ns`.
+///
+/// Smaller units are always truncated, never rounded, so a duration never
+/// appears longer than it is.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct HumanDuration;
+impl super::Style for HumanDuration {
+    type Role = super::Base;
+}
+super::derive!(struct HumanDuration);
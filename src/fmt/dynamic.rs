@@ -0,0 +1,85 @@
+use super::{args::Arguments, args::Var, Display, Format, Formats, Result, Write};
+
+#[cfg(any(feature = "alloc", test))]
+use alloc::boxed::Box;
+
+/// Some data paired with the style to format it in.
+///
+/// Implements [`DynFormat`], so can be stored alongside data of other types
+/// and styles, e.g. in a <code>Vec<[BoxedFormat]></code>. Also implements
+/// [`core::fmt::Display`] and [`core::fmt::Debug`], for passing to std APIs.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Styled<T, S> {
+    /// The data to format.
+    pub data: T,
+
+    /// The style to format the data in.
+    pub style: S,
+}
+
+impl<T, S> Styled<T, S> {
+    /// Pair `data` with `style`.
+    pub const fn new(data: T, style: S) -> Self {
+        Self { data, style }
+    }
+
+    /// Box the data and style into a [`BoxedFormat`].
+    #[cfg(any(feature = "alloc", test))]
+    pub fn boxed<'a>(self) -> BoxedFormat<'a>
+    where
+        T: 'a,
+        S: Formats<T> + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+/// An object-safe form of [`Format`], where the style is already known.
+///
+/// `dyn DynFormat` implements <code>Format<[Display]></code>, so can be used
+/// directly in macros like [`format`](crate::format).
+pub trait DynFormat {
+    /// Formats the value given a [writer](Write).
+    fn fmt(&self, f: &mut dyn Write) -> Result;
+}
+
+/// A boxed [`DynFormat`].
+#[cfg(any(feature = "alloc", test))]
+pub type BoxedFormat<'a> = Box<dyn DynFormat + 'a>;
+
+impl<T, S: Formats<T>> DynFormat for Styled<T, S> {
+    fn fmt(&self, f: &mut dyn Write) -> Result {
+        self.style.format(f, &self.data)
+    }
+}
+
+impl DynFormat for Var<'_> {
+    fn fmt(&self, f: &mut dyn Write) -> Result {
+        self.call(f)
+    }
+}
+
+impl DynFormat for Arguments<'_> {
+    fn fmt(&self, f: &mut dyn Write) -> Result {
+        self.write(f)
+    }
+}
+
+impl<D: DynFormat + ?Sized> DynFormat for &D {
+    fn fmt(&self, f: &mut dyn Write) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl<D: DynFormat + ?Sized> DynFormat for Box<D> {
+    fn fmt(&self, f: &mut dyn Write) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+impl Format<Display> for dyn DynFormat + '_ {
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        DynFormat::fmt(self, f)
+    }
+}
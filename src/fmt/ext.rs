@@ -0,0 +1,234 @@
+use super::{
+    Bits, Both, Delim, Display, Format, Group, Hex, Pad, Prefix, Quote, Radix, Sci, Sign, Style,
+    Suffix, Surround, Then, Truncate, Wrap,
+};
+
+/// Methods for wrapping any style in modifiers (and other wrappers), so that
+/// they read in the order they're applied; e.g.
+/// `Hex(false).prefixed("0x").pad_right('0', 9)` is
+/// `Pad::right('0', 9, Prefix("0x", Hex(false)))`.
+///
+/// Automatically implemented for all styles.
+pub trait StyleExt: Style + Sized {
+    /// Print `prefix` before the data.
+    ///
+    /// Shorthand for `Prefix(prefix, self)`.
+    fn prefixed<P: Format<Display>>(self, prefix: P) -> Prefix<P, Self> {
+        Prefix(prefix, self)
+    }
+
+    /// Print `suffix` after the data.
+    ///
+    /// Shorthand for `Suffix(suffix, self)`.
+    fn suffixed<P: Format<Display>>(self, suffix: P) -> Suffix<P, Self> {
+        Suffix(suffix, self)
+    }
+
+    /// Print `left` before the data and `right` after it.
+    ///
+    /// Shorthand for `Surround(left, right, self)`.
+    fn surrounded<L, R>(self, left: L, right: R) -> Surround<L, R, Self>
+    where
+        L: Format<Display>,
+        R: Format<Display>,
+    {
+        Surround(left, right, self)
+    }
+
+    /// Surround the data with `delim`, escaping it within.
+    ///
+    /// Shorthand for `Quote { delim, style: self }`.
+    fn quoted(self, delim: Delim) -> Quote<Self> {
+        Quote { delim, style: self }
+    }
+
+    /// Pad with `with`, placing the data on the left.
+    ///
+    /// Shorthand for `Pad::left(with, count, self)`.
+    fn pad_left<F: Format<Display>>(self, with: F, count: usize) -> Pad<Self, F> {
+        Pad::left(with, count, self)
+    }
+
+    /// Pad with `with`, placing the data in the center.
+    ///
+    /// Shorthand for `Pad::center(with, count, self)`.
+    fn pad_center<F: Format<Display>>(self, with: F, count: usize) -> Pad<Self, F> {
+        Pad::center(with, count, self)
+    }
+
+    /// Pad with `with`, placing the data on the right.
+    ///
+    /// Shorthand for `Pad::right(with, count, self)`.
+    fn pad_right<F: Format<Display>>(self, with: F, count: usize) -> Pad<Self, F> {
+        Pad::right(with, count, self)
+    }
+
+    /// Cut the data off after `max` characters.
+    ///
+    /// Shorthand for `Truncate::new(max, self)`.
+    fn truncated(self, max: usize) -> Truncate<Self> {
+        Truncate::new(max, self)
+    }
+
+    /// Cut the data off after `max` characters, ending with `ellipsis` if
+    /// anything was cut.
+    ///
+    /// Shorthand for `Truncate::ellipsis(max, ellipsis, self)`, so it panics
+    /// the same way if `ellipsis` is longer than 8 characters.
+    fn ellipsized(self, max: usize, ellipsis: &'static str) -> Truncate<Self> {
+        Truncate::ellipsis(max, ellipsis, self)
+    }
+
+    /// Wrap the data into lines at most `width` columns wide.
+    ///
+    /// Shorthand for `Wrap::new(width, self)`.
+    fn wrapped(self, width: usize) -> Wrap<Self> {
+        Wrap::new(width, self)
+    }
+
+    /// Separate every `every` digits with `sep`.
+    ///
+    /// Shorthand for `Group::new(sep, every, self)`.
+    fn grouped(self, sep: char, every: usize) -> Group<Self> {
+        Group::new(sep, every, self)
+    }
+
+    /// Force a `+` before non-negative numbers.
+    ///
+    /// Shorthand for `Sign(self)`.
+    fn signed(self) -> Sign<Self> {
+        Sign(self)
+    }
+
+    /// Format the data with this style, then with `next`.
+    ///
+    /// Shorthand for `Then(self, next)`.
+    fn then<B: Style>(self, next: B) -> Then<Self, B> {
+        Then(self, next)
+    }
+
+    /// Format the data with this style, then `sep`, then with `other`.
+    ///
+    /// Shorthand for `Both::new(self, sep, other)`.
+    fn both<P: Format<Display>, B: Style>(self, sep: P, other: B) -> Both<Self, P, B> {
+        Both::new(self, sep, other)
+    }
+}
+
+impl<S: Style> StyleExt for S {}
+
+/// A style with uppercase and lowercase variants, e.g. [`Hex`].
+///
+/// Implemented by wrappers whose inner style has a case, so it can be set
+/// from the outside; e.g. `Hex(false).prefixed("0x").upper()` is
+/// `Prefix("0x", Hex(true))`.
+pub trait Case: Style + Sized {
+    /// Set whether or not output should be uppercase.
+    fn with_case(self, upper: bool) -> Self;
+
+    /// Make output uppercase.
+    fn upper(self) -> Self {
+        self.with_case(true)
+    }
+
+    /// Make output lowercase.
+    fn lower(self) -> Self {
+        self.with_case(false)
+    }
+}
+
+impl Case for Hex {
+    fn with_case(self, upper: bool) -> Self {
+        Self(upper)
+    }
+}
+
+impl Case for Radix {
+    fn with_case(self, upper: bool) -> Self {
+        Self { upper, ..self }
+    }
+}
+
+impl Case for Sci {
+    fn with_case(self, upper: bool) -> Self {
+        Self { upper, ..self }
+    }
+}
+
+impl<S: Case> Case for Bits<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
+
+impl<S: Case> Case for Sign<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self(self.0.with_case(upper))
+    }
+}
+
+impl<P: Format<Display>, S: Case> Case for Prefix<P, S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self(self.0, self.1.with_case(upper))
+    }
+}
+
+impl<P: Format<Display>, S: Case> Case for Suffix<P, S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self(self.0, self.1.with_case(upper))
+    }
+}
+
+impl<L: Format<Display>, R: Format<Display>, S: Case> Case for Surround<L, R, S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self(self.0, self.1, self.2.with_case(upper))
+    }
+}
+
+impl<S: Case> Case for Quote<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
+
+impl<S: Case, F: Format<Display>> Case for Pad<S, F> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
+
+impl<S: Case> Case for Truncate<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
+
+impl<S: Case> Case for Wrap<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
+
+impl<S: Case> Case for Group<S> {
+    fn with_case(self, upper: bool) -> Self {
+        Self {
+            style: self.style.with_case(upper),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/// A repr for formatting numbers in decimal with a fixed number of digits
+/// after the decimal point. Implemented for all integers and floats.
+///
+/// Floats are rounded to the nearest representable value, with ties rounding
+/// to even; integers are padded with zeros.
+///
+/// Equivalent of [`core::fmt::Display`] with a precision (e.g. `{:.3}`).
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Fixed {
+    /// The number of digits after the decimal point.
+    pub precision: usize,
+}
+impl super::Style for Fixed {
+    type Role = super::Base;
+}
+super::derive!(struct Fixed { precision });
+
+impl Fixed {
+    /// Create a new `Fixed` with the given precision.
+    pub const fn new(precision: usize) -> Self {
+        Self { precision }
+    }
+}
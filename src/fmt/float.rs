@@ -0,0 +1,589 @@
+//! Conversion of floating-point numbers to decimal.
+//!
+//! Digits are generated exactly with a small fixed-size bignum, in the style of
+//! Steele & White's / Dragon4's "free-format" algorithm: the shortest digit
+//! string that still rounds back to the original float is produced, so output
+//! matches `core::fmt` without pulling in any of its machinery.
+
+use core::cmp::Ordering;
+
+use super::{Error, Result, Write};
+
+/// The maximum number of significant digits needed to round-trip an `f64`.
+pub const MAX_SIG_DIGITS: usize = 17;
+
+/// The maximum number of significant digits in the exact value of an `f64`
+/// (767), rounded up.
+pub const MAX_EXACT_DIGITS: usize = 800;
+
+/// A float, broken down for formatting.
+pub enum Decoded {
+    Nan,
+    Infinite,
+    Zero,
+    Finite(Finite),
+}
+
+/// A finite, nonzero float.
+///
+/// The value is `mant * 2^exp`, and any value in
+/// `(mant - minus) * 2^exp ..= (mant + plus) * 2^exp` rounds to it; the
+/// bounds themselves only do so if `inclusive`.
+pub struct Finite {
+    mant: u64,
+    minus: u64,
+    plus: u64,
+    exp: i16,
+    inclusive: bool,
+}
+
+impl Finite {
+    /// Create a new `Finite` from the float's integer mantissa and exponent.
+    /// `min_mant` is the mantissa of the smallest normal value; subnormals
+    /// must already have their mantissa doubled.
+    fn new(mant: u64, exp: i16, subnormal: bool, min_mant: u64) -> Self {
+        let inclusive = mant & 1 == 0;
+
+        if subnormal {
+            // subnormal: neighbours are `mant - 2` and `mant + 2`
+            Self {
+                mant,
+                minus: 1,
+                plus: 1,
+                exp,
+                inclusive,
+            }
+        } else if mant == min_mant {
+            // the neighbour below is only half as far away as the one above
+            Self {
+                mant: mant << 2,
+                minus: 1,
+                plus: 2,
+                exp: exp - 2,
+                inclusive,
+            }
+        } else {
+            // neighbours are `mant - 1` and `mant + 1`: double everything so
+            // the halfway points are integers
+            Self {
+                mant: mant << 1,
+                minus: 1,
+                plus: 1,
+                exp: exp - 1,
+                inclusive,
+            }
+        }
+    }
+}
+
+/// A float that can be formatted in decimal.
+pub trait Float: Copy {
+    /// Break the float down, returning whether or not it's negative.
+    fn decode(self) -> (bool, Decoded);
+
+    /// Whether or not [`Debug`](super::Debug) should use scientific notation.
+    fn debug_exp(self) -> bool;
+}
+
+impl Float for f64 {
+    fn decode(self) -> (bool, Decoded) {
+        let bits = self.to_bits();
+        let negative = bits >> 63 != 0;
+        let exp = ((bits >> 52) & 0x7ff) as i16;
+        let frac = bits & ((1 << 52) - 1);
+
+        let decoded = match (exp, frac) {
+            (0x7ff, 0) => Decoded::Infinite,
+            (0x7ff, _) => Decoded::Nan,
+            (0, 0) => Decoded::Zero,
+            (0, _) => Decoded::Finite(Finite::new(frac << 1, -1075, true, 1 << 52)),
+            _ => Decoded::Finite(Finite::new(frac | 1 << 52, exp - 1075, false, 1 << 52)),
+        };
+
+        (negative, decoded)
+    }
+
+    fn debug_exp(self) -> bool {
+        let abs = self.abs();
+        (abs != 0.0 && abs < 1e-4) || abs >= 1e16
+    }
+}
+
+impl Float for f32 {
+    fn decode(self) -> (bool, Decoded) {
+        let bits = self.to_bits();
+        let negative = bits >> 31 != 0;
+        let exp = ((bits >> 23) & 0xff) as i16;
+        let frac = (bits & ((1 << 23) - 1)) as u64;
+
+        let decoded = match (exp, frac) {
+            (0xff, 0) => Decoded::Infinite,
+            (0xff, _) => Decoded::Nan,
+            (0, 0) => Decoded::Zero,
+            (0, _) => Decoded::Finite(Finite::new(frac << 1, -150, true, 1 << 23)),
+            _ => Decoded::Finite(Finite::new(frac | 1 << 23, exp - 150, false, 1 << 23)),
+        };
+
+        (negative, decoded)
+    }
+
+    fn debug_exp(self) -> bool {
+        let abs = self.abs();
+        (abs != 0.0 && abs < 1e-4) || abs >= 1e16
+    }
+}
+
+/// A fixed-size unsigned bignum, large enough for any `f64` conversion.
+#[derive(Clone)]
+struct Big {
+    size: usize,
+    limbs: [u32; 40],
+}
+
+impl Big {
+    fn new(x: u64) -> Self {
+        let mut limbs = [0; 40];
+        limbs[0] = x as u32;
+        limbs[1] = (x >> 32) as u32;
+
+        let mut big = Self { size: 2, limbs };
+        big.trim();
+        big
+    }
+
+    fn trim(&mut self) {
+        while self.size > 0 && self.limbs[self.size - 1] == 0 {
+            self.size -= 1;
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.size == 0
+    }
+
+    fn add(&mut self, other: &Self) -> &mut Self {
+        let size = self.size.max(other.size);
+        let mut carry = false;
+        for i in 0..size {
+            let (x, c1) = self.limbs[i].overflowing_add(other.limbs[i]);
+            let (x, c2) = x.overflowing_add(carry as u32);
+            self.limbs[i] = x;
+            carry = c1 || c2;
+        }
+
+        self.size = size;
+        if carry {
+            self.limbs[size] = 1;
+            self.size += 1;
+        }
+
+        self
+    }
+
+    /// Subtract `other` from `self`. `self` must not be less than `other`.
+    fn sub(&mut self, other: &Self) -> &mut Self {
+        let mut borrow = false;
+        for i in 0..self.size {
+            let (x, b1) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (x, b2) = x.overflowing_sub(borrow as u32);
+            self.limbs[i] = x;
+            borrow = b1 || b2;
+        }
+
+        debug_assert!(!borrow);
+        self.trim();
+        self
+    }
+
+    fn mul_small(&mut self, by: u32) -> &mut Self {
+        let mut carry = 0;
+        for limb in &mut self.limbs[..self.size] {
+            let x = *limb as u64 * by as u64 + carry;
+            *limb = x as u32;
+            carry = x >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs[self.size] = carry as u32;
+            self.size += 1;
+        }
+
+        self
+    }
+
+    fn mul_pow2(&mut self, n: usize) -> &mut Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        let limbs = n / 32;
+        let bits = n % 32;
+
+        if bits > 0 {
+            self.limbs[self.size] = 0;
+            for i in (0..self.size).rev() {
+                self.limbs[i + 1] |= self.limbs[i] >> (32 - bits);
+                self.limbs[i] <<= bits;
+            }
+            self.size += 1;
+        }
+
+        if limbs > 0 {
+            self.limbs.copy_within(..self.size, limbs);
+            self.limbs[..limbs].fill(0);
+            self.size += limbs;
+        }
+
+        self.trim();
+        self
+    }
+
+    fn mul_pow10(&mut self, mut n: usize) -> &mut Self {
+        while n >= 9 {
+            self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+
+        self.mul_small(10_u32.pow(n as u32))
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.size.cmp(&other.size).then_with(|| {
+            self.limbs[..self.size]
+                .iter()
+                .rev()
+                .cmp(other.limbs[..other.size].iter().rev())
+        })
+    }
+}
+
+/// Scales the float such that `mant / scale * 10^k` is its value, where `k` is
+/// returned alongside. `k` is only an estimate, and may be off by one.
+fn scale(d: &Finite) -> (Big, Big, Big, Big, i16) {
+    let mut mant = Big::new(d.mant);
+    let mut minus = Big::new(d.minus);
+    let mut plus = Big::new(d.plus);
+    let mut scale = Big::new(1);
+
+    // `mant * 2^exp` lies in `[2^(bits - 1), 2^bits)`, so this is
+    // `floor((bits - 1) * log10(2)) + 1`
+    let bits = (64 - d.mant.leading_zeros()) as i64 + d.exp as i64;
+    let k = (((bits - 1) * 1_292_913_986) >> 32) as i16 + 1;
+
+    if d.exp < 0 {
+        scale.mul_pow2(-d.exp as usize);
+    } else {
+        mant.mul_pow2(d.exp as usize);
+        minus.mul_pow2(d.exp as usize);
+        plus.mul_pow2(d.exp as usize);
+    }
+
+    if k >= 0 {
+        scale.mul_pow10(k as usize);
+    } else {
+        mant.mul_pow10(-k as usize);
+        minus.mul_pow10(-k as usize);
+        plus.mul_pow10(-k as usize);
+    }
+
+    (mant, minus, plus, scale, k)
+}
+
+/// Computes `floor(mant / scale)`, leaving the remainder in `mant`. The result
+/// must be less than 10.
+fn digit(mant: &mut Big, scale: &Big) -> u8 {
+    let mut d = 0;
+    while mant.cmp(scale) != Ordering::Less {
+        mant.sub(scale);
+        d += 1;
+    }
+
+    debug_assert!(d < 10);
+    d
+}
+
+/// Increment the decimal number in `buf`, returning `true` if it overflowed
+/// (in which case `buf` becomes `1000...`).
+pub fn round_up(buf: &mut [u8]) -> bool {
+    match buf.iter().rposition(|&d| d != b'9') {
+        Some(i) => {
+            buf[i] += 1;
+            buf[i + 1..].fill(b'0');
+            false
+        }
+        None => {
+            buf.fill(b'0');
+            if let Some(first) = buf.first_mut() {
+                *first = b'1';
+            }
+            true
+        }
+    }
+}
+
+/// Generates the shortest digits that round-trip to the float, returning the
+/// number of digits written into `buf` and the exponent `k` such that the
+/// value is `0.d1d2d3... * 10^k`.
+pub fn shortest(d: &Finite, buf: &mut [u8; MAX_SIG_DIGITS]) -> (usize, i16) {
+    let (mut mant, mut minus, mut plus, scale_by, mut k) = scale(d);
+    let mut scale = scale_by;
+
+    // `a` is below `b`, or equal to it if the bounds are inclusive
+    let within = |a: &Big, b: &Big| match a.cmp(b) {
+        Ordering::Less => true,
+        Ordering::Equal => d.inclusive,
+        Ordering::Greater => false,
+    };
+
+    // the upper bound must be below `10^k`
+    while within(&scale, mant.clone().add(&plus)) {
+        scale.mul_small(10);
+        k += 1;
+    }
+
+    let mut i = 0;
+    let (down, up) = loop {
+        mant.mul_small(10);
+        minus.mul_small(10);
+        plus.mul_small(10);
+
+        buf[i] = b'0' + digit(&mut mant, &scale);
+        i += 1;
+
+        // can we stop here, and round down or up to the remaining interval?
+        let down = within(&mant, &minus);
+        let up = within(&scale, mant.clone().add(&plus));
+        if down || up {
+            break (down, up);
+        }
+    };
+
+    if up && (!down || mant.mul_pow2(1).cmp(&scale) != Ordering::Less) && round_up(&mut buf[..i]) {
+        i = 1;
+        k += 1;
+    }
+
+    while i > 1 && buf[i - 1] == b'0' {
+        i -= 1;
+    }
+
+    (i, k)
+}
+
+/// Where to stop generating digits in [`exact`].
+#[derive(Clone, Copy)]
+pub enum Limit {
+    /// Generate this many significant digits.
+    Digits(usize),
+
+    /// Generate digits down to, and including, the place of `10^exp`.
+    Exp(i16),
+}
+
+/// Generates the exact digits of the float, rounded (ties to even) according
+/// to `limit`. Returns the number of digits written into `buf` and the
+/// exponent `k` such that the value is `0.d1d2d3... * 10^k`; if no digits were
+/// written, the value rounded to zero.
+pub fn exact(d: &Finite, buf: &mut [u8; MAX_EXACT_DIGITS], limit: Limit) -> (usize, i16) {
+    let (mut mant, _, _, mut scale, mut k) = scale(d);
+
+    while mant.cmp(&scale) != Ordering::Less {
+        scale.mul_small(10);
+        k += 1;
+    }
+
+    let len = match limit {
+        Limit::Digits(n) => n as isize,
+        Limit::Exp(exp) => k as isize - exp as isize,
+    };
+
+    if len < 0 {
+        return (0, k);
+    } else if len == 0 {
+        // the value is below `10^k`, so it can only round up to that
+        return if mant.mul_pow2(1).cmp(&scale) == Ordering::Greater {
+            buf[0] = b'1';
+            (1, k + 1)
+        } else {
+            (0, k)
+        };
+    }
+
+    let len = (len as usize).min(buf.len());
+    let mut i = 0;
+    while i < len && !mant.is_zero() {
+        mant.mul_small(10);
+        buf[i] = b'0' + digit(&mut mant, &scale);
+        i += 1;
+    }
+
+    let round = mant.mul_pow2(1).cmp(&scale);
+    let odd = i > 0 && buf[i - 1] % 2 == 1;
+    if (round == Ordering::Greater || (round == Ordering::Equal && odd)) && round_up(&mut buf[..i])
+    {
+        i = 1;
+        k += 1;
+    }
+
+    (i, k)
+}
+
+/// Interprets a buffer of ASCII digits as a `str`.
+fn digits(buf: &[u8]) -> &str {
+    // SAFETY: only ASCII digits are ever written into digit buffers
+    unsafe { core::str::from_utf8_unchecked(buf) }
+}
+
+fn zeros(f: &mut dyn Write, mut n: usize) -> Result {
+    const ZEROS: &str = "0000000000000000";
+    while n > ZEROS.len() {
+        f.write_str(ZEROS)?;
+        n -= ZEROS.len();
+    }
+    f.write_str(&ZEROS[..n])
+}
+
+/// Writes `0.<buf> * 10^k` in plain decimal notation, with at least
+/// `min_frac` digits after the decimal point.
+pub fn write_decimal(f: &mut dyn Write, buf: &[u8], k: i16, min_frac: usize) -> Result {
+    let frac = if k <= 0 {
+        f.write_str("0.")?;
+        zeros(f, -k as usize)?;
+        f.write_str(digits(buf))?;
+        buf.len() + (-k as usize)
+    } else if (k as usize) < buf.len() {
+        let (int, frac) = buf.split_at(k as usize);
+        f.write_str(digits(int))?;
+        f.write_char('.')?;
+        f.write_str(digits(frac))?;
+        frac.len()
+    } else {
+        f.write_str(digits(buf))?;
+        zeros(f, k as usize - buf.len())?;
+        if min_frac > 0 {
+            f.write_char('.')?;
+        }
+        0
+    };
+
+    zeros(f, min_frac.saturating_sub(frac))
+}
+
+/// Writes `0.<buf> * 10^k` in scientific notation, with at least `min_frac`
+/// digits after the decimal point.
+pub fn write_exp(f: &mut dyn Write, buf: &[u8], k: i16, min_frac: usize, upper: bool) -> Result {
+    let (first, rest) = buf.split_at(1);
+    f.write_str(digits(first))?;
+    if !rest.is_empty() || min_frac > 0 {
+        f.write_char('.')?;
+        f.write_str(digits(rest))?;
+        zeros(f, min_frac.saturating_sub(rest.len()))?;
+    }
+
+    f.write_char(if upper { 'E' } else { 'e' })?;
+    let exp = k - 1;
+    if exp < 0 {
+        f.write_char('-')?;
+    }
+
+    let mut buf = [0; 5];
+    let mut i = buf.len();
+    let mut exp = exp.unsigned_abs();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (exp % 10) as u8;
+        exp /= 10;
+        if exp == 0 {
+            break;
+        }
+    }
+    f.write_str(digits(&buf[i..]))
+}
+
+/// Writes the sign and any non-finite value, returning the value if there's
+/// more to write (i.e. if it's zero or finite).
+fn special(
+    f: &mut dyn Write,
+    (negative, decoded): (bool, Decoded),
+) -> core::result::Result<Option<Decoded>, Error> {
+    match decoded {
+        Decoded::Nan => f.write_str("NaN").map(|_| None),
+        decoded => {
+            if negative {
+                f.write_char('-')?;
+            }
+
+            match decoded {
+                Decoded::Infinite => f.write_str("inf").map(|_| None),
+                decoded => Ok(Some(decoded)),
+            }
+        }
+    }
+}
+
+/// Formats a float the same as `core::fmt::Display`.
+pub fn display<F: Float>(f: &mut dyn Write, x: F) -> Result {
+    match special(f, x.decode())? {
+        Some(Decoded::Finite(d)) => {
+            let mut buf = [0; MAX_SIG_DIGITS];
+            let (len, k) = shortest(&d, &mut buf);
+            write_decimal(f, &buf[..len], k, 0)
+        }
+        Some(_) => f.write_char('0'),
+        None => Ok(()),
+    }
+}
+
+/// Formats a float the same as `core::fmt::Debug`.
+pub fn debug<F: Float>(f: &mut dyn Write, x: F) -> Result {
+    match special(f, x.decode())? {
+        Some(Decoded::Finite(d)) => {
+            let mut buf = [0; MAX_SIG_DIGITS];
+            let (len, k) = shortest(&d, &mut buf);
+            if x.debug_exp() {
+                write_exp(f, &buf[..len], k, 0, false)
+            } else {
+                write_decimal(f, &buf[..len], k, 1)
+            }
+        }
+        Some(_) => f.write_str("0.0"),
+        None => Ok(()),
+    }
+}
+
+/// Formats a float in decimal with exactly `precision` digits after the
+/// decimal point.
+pub fn fixed<F: Float>(f: &mut dyn Write, x: F, precision: usize) -> Result {
+    match special(f, x.decode())? {
+        Some(Decoded::Finite(d)) => {
+            let mut buf = [0; MAX_EXACT_DIGITS];
+            let limit = Limit::Exp(-(precision.min(i16::MAX as usize) as i16));
+            match exact(&d, &mut buf, limit) {
+                (0, _) => write_decimal(f, b"0", 1, precision),
+                (len, k) => write_decimal(f, &buf[..len], k, precision),
+            }
+        }
+        Some(_) => write_decimal(f, b"0", 1, precision),
+        None => Ok(()),
+    }
+}
+
+/// Formats a float in scientific notation, with either the shortest
+/// round-tripping digits or exactly `precision` digits after the decimal
+/// point.
+pub fn sci<F: Float>(f: &mut dyn Write, x: F, precision: Option<usize>, upper: bool) -> Result {
+    match (special(f, x.decode())?, precision) {
+        (Some(Decoded::Finite(d)), None) => {
+            let mut buf = [0; MAX_SIG_DIGITS];
+            let (len, k) = shortest(&d, &mut buf);
+            write_exp(f, &buf[..len], k, 0, upper)
+        }
+        (Some(Decoded::Finite(d)), Some(precision)) => {
+            let mut buf = [0; MAX_EXACT_DIGITS];
+            let (len, k) = exact(&d, &mut buf, Limit::Digits(precision + 1));
+            write_exp(f, &buf[..len], k, precision, upper)
+        }
+        (Some(_), precision) => write_exp(f, b"0", 1, precision.unwrap_or(0), upper),
+        (None, _) => Ok(()),
+    }
+}
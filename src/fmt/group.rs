@@ -0,0 +1,166 @@
+use super::{Formats, Modified, Modifier, Result, Style, Write};
+
+/// Insert a separator between every `every` digits of the integer part of a
+/// number, counting from the right; e.g. `1048576` grouped by 3 with `,` is
+/// `1,048,576`.
+///
+/// The integer part is taken to be the first run of digits in the inner
+/// style's [radix](Style::radix), after an optional sign, so this works for
+/// any numeric style (e.g. [`Display`](super::Display), [`Hex`](super::Hex),
+/// [`Sci`](super::Sci)), but should be placed inside any
+/// [`Prefix`](super::Prefix).
+///
+/// Since the digits must be counted before they are written, the data is
+/// formatted twice.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Group<S> {
+    /// The separator to insert.
+    pub sep: char,
+
+    /// The number of digits in each group. If 0, no separators are inserted.
+    pub every: usize,
+
+    /// The style being wrapped by this modifier.
+    pub style: S,
+}
+super::derive!(struct Group<S!> { sep, every, style });
+
+impl<S: Style> Group<S> {
+    /// Group digits by `every`, separated by `sep`.
+    ///
+    /// Shorthand for `Group { sep, every, style }`.
+    pub const fn new(sep: char, every: usize, style: S) -> Self {
+        Self { sep, every, style }
+    }
+
+    /// Group digits in thousands, separated by `sep`.
+    ///
+    /// Shorthand for `Group { sep, every: 3, style }`.
+    pub const fn thousands(sep: char, style: S) -> Self {
+        Self::new(sep, 3, style)
+    }
+}
+
+impl<S: Style> Style for Group<S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+
+impl<S: Style> Modifier for Group<S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        if self.every == 0 {
+            return self.style.format(f, data);
+        }
+
+        let run = Run::new(self.style.radix());
+        let mut counter = Counter { run, digits: 0 };
+        self.style.format(&mut counter, data)?;
+
+        self.style.format(
+            &mut Grouper {
+                f,
+                run,
+                left: counter.digits,
+                first: true,
+                sep: self.sep,
+                every: self.every,
+            },
+            data,
+        )
+    }
+}
+
+/// Tracks whether or not output is within the integer part of a number.
+#[derive(Clone, Copy)]
+struct Run {
+    radix: u32,
+    started: bool,
+    done: bool,
+}
+
+impl Run {
+    fn new(radix: u32) -> Self {
+        Self {
+            radix: radix.clamp(2, 36),
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Returns whether or not `ch` is a digit of the integer part.
+    fn digit(&mut self, ch: char) -> bool {
+        if self.done || (!self.started && (ch == '-' || ch == '+')) {
+            return false;
+        }
+
+        self.started = true;
+        self.done = !ch.is_digit(self.radix);
+        !self.done
+    }
+}
+
+/// Counts the digits in the integer part of a number.
+struct Counter {
+    run: Run,
+    digits: usize,
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, data: &str) -> Result {
+        for ch in data.chars() {
+            if self.run.done {
+                break;
+            } else if self.run.digit(ch) {
+                self.digits += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Inserts separators into the integer part of a number.
+struct Grouper<'w> {
+    f: &'w mut dyn Write,
+    run: Run,
+    left: usize,
+    first: bool,
+    sep: char,
+    every: usize,
+}
+
+impl Write for Grouper<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        if self.run.done {
+            return self.f.write_str(data);
+        }
+
+        for ch in data.chars() {
+            self.write_char(ch)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> Result {
+        if self.run.digit(ch) {
+            if !self.first && self.left.is_multiple_of(self.every) {
+                self.f.write_char(self.sep)?;
+            }
+
+            self.first = false;
+            self.left = self.left.saturating_sub(1);
+        }
+
+        self.f.write_char(ch)
+    }
+}
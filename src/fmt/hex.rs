@@ -2,6 +2,9 @@ use super::Prefix;
 
 /// A repr for formatting data in hexadecimal. Implemented for all integers.
 ///
+/// Negative numbers are written as a `-` followed by their magnitude; see
+/// [`Bits`](super::Bits) for two's complement and full-width output.
+///
 /// The inner `bool` specifies whether or not output should be uppercase.
 ///
 /// Unlike [`Debug`](super::Debug) and [`Pretty`](super::Pretty), cannot be
@@ -10,7 +13,13 @@ use super::Prefix;
 /// Equivalent of [`core::fmt::LowerHex`] and [`core::fmt::UpperHex`].
 #[derive(Default, Clone, Copy)]
 pub struct Hex(pub bool);
-impl super::Style for Hex {}
+impl super::Style for Hex {
+    type Role = super::Base;
+
+    fn radix(&self) -> u32 {
+        16
+    }
+}
 super::derive!(struct Hex(uppercase));
 
 impl Hex {
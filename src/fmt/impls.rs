@@ -1,10 +1,12 @@
-use super::{Binary, Debug, Display, Format, Hex, Pretty, Result, StdDebug, Style, Write};
+use core::cell::{Cell, RefCell};
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::time::Duration;
 
-crate::stylable![(), str, char, f32, f64, bool];
-crate::stylable!(for(T) [T]);
-crate::stylable!(for(T, const N: usize) [T; N]);
-crate::stylable!(for(T) Option<T>);
-crate::stylable!(for(T, E) core::result::Result<T, E>);
+use super::{
+    float, Binary, Bits, Bytes, Debug, Display, Error, Fixed, Format, Formats, Hex, HumanDuration,
+    Octal, Pretty, Radix, Result, Sci, Sign, Signed, Style, Write,
+};
 
 super::derive!(enum Option<T!> {
     None,
@@ -50,6 +52,12 @@ impl Format<Debug> for () {
     }
 }
 
+impl Format<Pretty> for () {
+    fn fmt(&self, f: &mut dyn Write, _: &Pretty) -> Result {
+        f.write_str("()")
+    }
+}
+
 impl Format<Debug> for str {
     fn fmt(&self, f: &mut dyn Write, _: &Debug) -> Result {
         f.write_char('"')?;
@@ -110,10 +118,166 @@ impl Format<Pretty> for bool {
     }
 }
 
-macro_rules! impl_int {
-    ($( $t:ident ),*) => {$(
-        $crate::stylable!($t);
+/// Formats the magnitude of an integer in scientific notation.
+fn sci_int(f: &mut dyn Write, negative: bool, mut x: u128, s: &Sci) -> Result {
+    if negative {
+        f.write_char('-')?;
+    }
+
+    let mut digits = [0_u8; 40];
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (x % 10) as u8;
+        x /= 10;
+        len += 1;
+        if x == 0 {
+            break;
+        }
+    }
+    digits[..len].reverse();
+
+    let mut k = len as i16;
+    match s.precision {
+        None => {
+            while len > 1 && digits[len - 1] == b'0' {
+                len -= 1;
+            }
+        }
+        Some(precision) if precision + 1 < len => {
+            // round half to even on the discarded digits
+            let (kept, rest) = digits[..len].split_at_mut(precision + 1);
+            let up = match rest[0].cmp(&b'5') {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => rest[1..].iter().any(|&d| d != b'0') || kept[precision] % 2 == 1,
+            };
+
+            len = precision + 1;
+            if up && float::round_up(kept) {
+                k += 1;
+            }
+        }
+        Some(_) => {}
+    }
+
+    float::write_exp(f, &digits[..len], k, s.precision.unwrap_or(0), s.upper)
+}
+
+/// Writes a byte count in human-readable units.
+fn bytes_int(f: &mut dyn Write, negative: bool, x: u128, s: &Bytes) -> Result {
+    const BINARY: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+    const DECIMAL: [&str; 9] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
+    let (base, units) = if s.binary {
+        (1024, &BINARY)
+    } else {
+        (1000, &DECIMAL)
+    };
+
+    if negative {
+        f.write_char('-')?;
+    }
+
+    if x < base {
+        x.fmt(f, &Display)?;
+        return f.write_str(" B");
+    }
+
+    let mut unit = 0;
+    let mut div = 1_u128;
+    while unit + 1 < units.len() && x / div >= base {
+        div *= base;
+        unit += 1;
+    }
+
+    // tenths of a unit, rounded half to even
+    let (q, r) = (x / div, x % div);
+    let mut tenths = q * 10 + r * 10 / div;
+    let rem = r * 10 % div;
+    if rem * 2 > div || (rem * 2 == div && tenths % 2 == 1) {
+        tenths += 1;
+    }
+
+    // e.g. 1023.97 KiB rounds up to 1 MiB
+    if tenths == base * 10 && unit + 1 < units.len() {
+        tenths = 10;
+        unit += 1;
+    }
+
+    (tenths / 10).fmt(f, &Display)?;
+    let tenth = tenths % 10;
+    if tenth != 0 {
+        f.write_char('.')?;
+        f.write_char((b'0' + tenth as u8) as char)?;
+    }
 
+    f.write_char(' ')?;
+    f.write_str(units[unit])
+}
+
+/// Writes the digits of `x` in any base from 2 to 36, failing for any other
+/// base.
+fn radix_int(f: &mut dyn Write, mut x: u128, base: u32, upper: bool) -> Result {
+    if !(2..=36).contains(&base) {
+        return Err(Error);
+    }
+
+    let mut digits = [0_u8; 128];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        let d = (x % base as u128) as u8;
+        digits[i] = if d < 10 {
+            b'0'
+        } else if upper {
+            b'A' - 10
+        } else {
+            b'a' - 10
+        } + d;
+        x /= base as u128;
+
+        if x == 0 {
+            break;
+        }
+    }
+
+    // SAFETY: only ASCII digits and letters are written into the buffer
+    f.write_str(unsafe { core::str::from_utf8_unchecked(&digits[i..]) })
+}
+
+/// Writes an integer in a power-of-two base (`2^shift`), given its raw bits
+/// and the number of bits in its type.
+fn bits_int<S>(
+    f: &mut dyn Write,
+    negative: bool,
+    raw: u128,
+    bits: u32,
+    s: &Bits<S>,
+    shift: u32,
+    upper: bool,
+) -> Result {
+    let x = if negative && s.signed == Signed::Magnitude {
+        f.write_char('-')?;
+
+        // sign-extend, negate, and truncate back to the original width
+        let mask = u128::MAX >> (128 - bits);
+        (raw | !mask).wrapping_neg() & mask
+    } else {
+        raw
+    };
+
+    if s.full {
+        let len = (128 - x.leading_zeros()).div_ceil(shift).max(1);
+        for _ in len..bits.div_ceil(shift) {
+            f.write_char('0')?;
+        }
+    }
+
+    radix_int(f, x, 1 << shift, upper)
+}
+
+macro_rules! impl_int {
+    ($( $t:ident : $u:ident ),*) => {$(
         impl Format<Debug> for $t {
             fn fmt(&self, f: &mut dyn Write, _: &Debug) -> Result {
                 // this will get optimized out for unsigned anyways
@@ -121,10 +285,11 @@ macro_rules! impl_int {
                 let mut x = if *self < 0 {
                     f.write_char('-')?;
 
-                    // to get around unsigned integers having no Neg
-                    (!*self) + 1
+                    // to get around unsigned integers having no Neg, and
+                    // `MIN` having no positive counterpart
+                    (*self as $u).wrapping_neg()
                 } else {
-                    *self
+                    *self as $u
                 };
 
                 let mut digits = [0_u8; 40];
@@ -164,98 +329,230 @@ macro_rules! impl_int {
 
         impl Format<Hex> for $t {
             fn fmt(&self, f: &mut dyn Write, s: &Hex) -> Result {
+                self.fmt(f, &s.magnitude())
+            }
+        }
+
+        impl Format<Binary> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Binary) -> Result {
+                self.fmt(f, &s.magnitude())
+            }
+        }
+
+        impl Format<Bits<Hex>> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Bits<Hex>) -> Result {
+                #[allow(unused_comparisons)]
+                bits_int(f, *self < 0, *self as $u as u128, $t::BITS, s, 4, s.style.0)
+            }
+        }
+
+        impl Format<Bits<Octal>> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Bits<Octal>) -> Result {
+                #[allow(unused_comparisons)]
+                bits_int(f, *self < 0, *self as $u as u128, $t::BITS, s, 3, false)
+            }
+        }
+
+        impl Format<Bits<Binary>> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Bits<Binary>) -> Result {
+                #[allow(unused_comparisons)]
+                bits_int(f, *self < 0, *self as $u as u128, $t::BITS, s, 1, false)
+            }
+        }
+
+        impl Format<Octal> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Octal) -> Result {
+                self.fmt(f, &s.magnitude())
+            }
+        }
+
+        impl Format<Radix> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Radix) -> Result {
+                if !(2..=36).contains(&s.base) {
+                    return Err(Error);
+                }
+
                 // this will get optimized out for unsigned anyways
                 #[allow(unused_comparisons)]
-                let mut x = if *self < 0 {
+                let x = if *self < 0 {
                     f.write_char('-')?;
-
-                    // to get around unsigned integers having no Neg
-                    (!*self) + 1
+                    (*self as $u).wrapping_neg()
                 } else {
-                    *self
+                    *self as $u
                 };
 
-                let mut digits = [0_u8; 40];
-                let mut i = 0;
-
-                digits[i] = (x % 16) as u8;
-                x /= 16;
-                i += 1;
+                radix_int(f, x as u128, s.base, s.upper)
+            }
+        }
 
-                while x > 0 {
-                    digits[i] = (x % 16) as u8;
-                    x /= 16;
-                    i += 1;
+        impl Format<Fixed> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Fixed) -> Result {
+                self.fmt(f, &Display)?;
+                if s.precision > 0 {
+                    f.write_char('.')?;
+                    for _ in 0..s.precision {
+                        f.write_char('0')?;
+                    }
                 }
+                Ok(())
+            }
+        }
 
-                while i > 0 {
-                    i -= 1;
-                    let x = digits[i];
-                    let ch = (if x < 10 { b'0' } else if s.0 { b'A' - 10 } else { b'a' - 10 } + x) as char;
-                    f.write_char(ch)?;
-                }
+        impl Format<Sci> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Sci) -> Result {
+                // this will get optimized out for unsigned anyways
+                #[allow(unused_comparisons)]
+                let negative = *self < 0;
+                let x = if negative {
+                    (*self as $u).wrapping_neg()
+                } else {
+                    *self as $u
+                };
 
-                Ok(())
+                sci_int(f, negative, x as u128, s)
             }
         }
 
-        impl Format<Binary> for $t {
-            fn fmt(&self, f: &mut dyn Write, _: &Binary) -> Result {
-                if *self == 0 {
-                    return f.write_char('0');
-                }
+        impl Format<Bytes> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Bytes) -> Result {
+                // this will get optimized out for unsigned anyways
+                #[allow(unused_comparisons)]
+                let negative = *self < 0;
+                let x = if negative {
+                    (*self as $u).wrapping_neg()
+                } else {
+                    *self as $u
+                };
+
+                bytes_int(f, negative, x as u128, s)
+            }
+        }
 
-                let mut x = self.reverse_bits();
-                let skip = self.leading_zeros();
-                x >>= skip;
-                for _ in 0..($t::BITS - skip) {
-                    f.write_char(if x & 1 == 1 {
-                        '1'
-                    } else {
-                        '0'
-                    })?;
-                    x >>= 1;
+        impl<S: Style> Format<Sign<S>> for $t
+        where
+            S: Formats<$t>,
+        {
+            fn fmt(&self, f: &mut dyn Write, s: &Sign<S>) -> Result {
+                // this will get optimized out for unsigned anyways
+                #[allow(unused_comparisons)]
+                if *self >= 0 {
+                    f.write_char('+')?;
                 }
-                Ok(())
+                s.0.format(f, self)
             }
         }
     )*};
 }
-impl_int![u8, u16, u32, u64, usize, i8, i16, i32, i64, isize];
+impl_int![
+    u8: u8,
+    u16: u16,
+    u32: u32,
+    u64: u64,
+    u128: u128,
+    usize: usize,
+    i8: u8,
+    i16: u16,
+    i32: u32,
+    i64: u64,
+    i128: u128,
+    isize: usize
+];
+
+macro_rules! impl_float {
+    ($( $t:ident ),*) => {$(
+        impl Format<Debug> for $t {
+            fn fmt(&self, f: &mut dyn Write, _: &Debug) -> Result {
+                float::debug(f, *self)
+            }
+        }
 
-impl Format<Debug> for f32 {
-    fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
-        StdDebug(*self).fmt(f, s)
-    }
-}
+        impl Format<Display> for $t {
+            fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+                float::display(f, *self)
+            }
+        }
 
-impl Format<Display> for f32 {
-    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
-        self.fmt(f, &Debug)
-    }
-}
+        impl Format<Pretty> for $t {
+            fn fmt(&self, f: &mut dyn Write, _: &Pretty) -> Result {
+                self.fmt(f, &Debug)
+            }
+        }
 
-impl Format<Pretty> for f32 {
-    fn fmt(&self, f: &mut dyn Write, _: &Pretty) -> Result {
-        self.fmt(f, &Debug)
-    }
-}
+        impl Format<Fixed> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Fixed) -> Result {
+                float::fixed(f, *self, s.precision)
+            }
+        }
 
-impl Format<Debug> for f64 {
-    fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
-        StdDebug(*self).fmt(f, s)
-    }
-}
+        impl Format<Sci> for $t {
+            fn fmt(&self, f: &mut dyn Write, s: &Sci) -> Result {
+                float::sci(f, *self, s.precision, s.upper)
+            }
+        }
 
-impl Format<Display> for f64 {
-    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
-        self.fmt(f, &Debug)
-    }
+        impl<S: Style> Format<Sign<S>> for $t
+        where
+            S: Formats<$t>,
+        {
+            fn fmt(&self, f: &mut dyn Write, s: &Sign<S>) -> Result {
+                if !self.is_nan() && self.is_sign_positive() {
+                    f.write_char('+')?;
+                }
+                s.0.format(f, self)
+            }
+        }
+    )*};
 }
+impl_float![f32, f64];
+
+impl Format<HumanDuration> for Duration {
+    fn fmt(&self, f: &mut dyn Write, _: &HumanDuration) -> Result {
+        let secs = self.as_secs();
+        if secs >= 60 {
+            let parts = [
+                (secs / 86400, 'd'),
+                (secs / 3600 % 24, 'h'),
+                (secs / 60 % 60, 'm'),
+                (secs % 60, 's'),
+            ];
+
+            // at least one of days, hours, or minutes is non-zero
+            let first = parts.iter().position(|&(n, _)| n != 0).unwrap_or(2);
+            for (i, &(n, unit)) in parts[first..].iter().enumerate() {
+                if i > 0 {
+                    f.write_char(' ')?;
+                    if n < 10 {
+                        f.write_char('0')?;
+                    }
+                }
 
-impl Format<Pretty> for f64 {
-    fn fmt(&self, f: &mut dyn Write, _: &Pretty) -> Result {
-        self.fmt(f, &Debug)
+                n.fmt(f, &Display)?;
+                f.write_char(unit)?;
+            }
+
+            return Ok(());
+        }
+
+        // less than a minute, so this can't overflow
+        let nanos = self.as_nanos() as u64;
+        let (scale, unit) = if nanos >= 1_000_000_000 {
+            (1_000_000_000, "s")
+        } else if nanos >= 1_000_000 {
+            (1_000_000, "ms")
+        } else if nanos >= 1_000 {
+            (1_000, "µs")
+        } else {
+            (1, "ns")
+        };
+
+        (nanos / scale).fmt(f, &Display)?;
+        let tenth = nanos % scale * 10 / scale;
+        if tenth != 0 {
+            f.write_char('.')?;
+            f.write_char((b'0' + tenth as u8) as char)?;
+        }
+
+        f.write_str(unit)
     }
 }
 
@@ -264,20 +561,7 @@ where
     T: Format<Debug>,
 {
     fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
-        f.write_char('[')?;
-
-        if !self.is_empty() {
-            let (lead, last) = self.split_at(self.len() - 1);
-
-            for x in lead {
-                x.fmt(f, s)?;
-                f.write_str(", ")?;
-            }
-
-            last[0].fmt(f, s)?;
-        }
-
-        f.write_char(']')
+        s.dbg_list(f).entries(self).finish()
     }
 }
 
@@ -286,25 +570,7 @@ where
     T: Format<Pretty>,
 {
     fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
-        f.write_char('[')?;
-
-        let nl = |f: &mut dyn Write, d| {
-            f.write_char('\n')?;
-            for _ in 0..d {
-                f.write_str("    ")?;
-            }
-            Ok(())
-        };
-
-        for x in self {
-            let d = s.0 + 1;
-            nl(f, d)?;
-            x.fmt(f, &Pretty(d))?;
-            f.write_char(',')?;
-        }
-
-        nl(f, s.0)?;
-        f.write_char(']')
+        s.group(self).dbg_list(f).entries(self).finish()
     }
 }
 
@@ -326,14 +592,142 @@ where
     }
 }
 
+macro_rules! impl_tuple {
+    ($( ($($t:ident $i:tt),+) ),* $(,)?) => {$(
+        impl<$($t: Format<Debug>),+> Format<Debug> for ($($t,)+) {
+            fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+                s.dbg_tuple(f, "")$(.field(&self.$i))+.finish()
+            }
+        }
+
+        impl<$($t: Format<Pretty>),+> Format<Pretty> for ($($t,)+) {
+            fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+                s.group(self).dbg_tuple(f, "")$(.field(&self.$i))+.finish()
+            }
+        }
+    )*};
+}
+impl_tuple![
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+];
+
+impl<T: Copy + Format<Debug>> Format<Debug> for Cell<T> {
+    fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+        s.dbg_struct(f, "Cell").field("value", &self.get()).finish()
+    }
+}
+
+impl<T: Copy + Format<Pretty>> Format<Pretty> for Cell<T> {
+    fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+        s.group(self)
+            .dbg_struct(f, "Cell")
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+impl<T: Format<Debug> + ?Sized> Format<Debug> for RefCell<T> {
+    fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+        let mut d = s.dbg_struct(f, "RefCell");
+        match self.try_borrow() {
+            Ok(value) => d.field("value", &&*value),
+            Err(_) => d.field_with("value", |f| f.write_str("<borrowed>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T: Format<Pretty> + ?Sized> Format<Pretty> for RefCell<T> {
+    fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+        let mut d = s.group(self).dbg_struct(f, "RefCell");
+        match self.try_borrow() {
+            Ok(value) => d.field("value", &&*value),
+            Err(_) => d.field_with("value", |f| f.write_str("<borrowed>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T: ?Sized> Format<Debug> for PhantomData<T> {
+    fn fmt(&self, f: &mut dyn Write, _: &Debug) -> Result {
+        f.write_str("PhantomData<")?;
+        f.write_str(core::any::type_name::<T>())?;
+        f.write_char('>')
+    }
+}
+
+impl<T: ?Sized> Format<Pretty> for PhantomData<T> {
+    fn fmt(&self, f: &mut dyn Write, _: &Pretty) -> Result {
+        self.fmt(f, &Debug)
+    }
+}
+
 #[cfg(any(feature = "alloc", test))]
 mod with_alloc {
-    use crate::fmt::{Debug, Display, Format, Pretty, Result, Write};
+    use crate::fmt::{Debug, Display, Format, Pretty, Result, Style, Write};
+
+    #[cfg(target_has_atomic = "ptr")]
+    use alloc::sync::Arc;
+    use alloc::{
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+        rc::Rc,
+        string::String,
+        vec::Vec,
+    };
+
+    impl<T, S> Format<S> for Box<T>
+    where
+        T: Format<S> + ?Sized,
+        S: Style,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &S) -> Result {
+            (**self).fmt(f, s)
+        }
+    }
 
-    use alloc::{string::String, vec::Vec};
+    impl<T, S> Format<S> for Rc<T>
+    where
+        T: Format<S> + ?Sized,
+        S: Style,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &S) -> Result {
+            (**self).fmt(f, s)
+        }
+    }
 
-    crate::stylable![String];
-    crate::stylable!(for(T: Format<Debug>) Vec<T>);
+    #[cfg(target_has_atomic = "ptr")]
+    impl<T, S> Format<S> for Arc<T>
+    where
+        T: Format<S> + ?Sized,
+        S: Style,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &S) -> Result {
+            (**self).fmt(f, s)
+        }
+    }
+
+    impl<B, S> Format<S> for Cow<'_, B>
+    where
+        B: Format<S> + ToOwned + ?Sized,
+        S: Style,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &S) -> Result {
+            (**self).fmt(f, s)
+        }
+    }
 
     impl Format<Debug> for String {
         fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
@@ -371,6 +765,54 @@ mod with_alloc {
         }
     }
 
+    macro_rules! impl_list {
+        ($($name:ident $dbg:ident),* $(,)?) => {$(
+            impl<T> Format<Debug> for $name<T>
+            where
+                T: Format<Debug>,
+            {
+                fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+                    s.$dbg(f).entries(self).finish()
+                }
+            }
+
+            impl<T> Format<Pretty> for $name<T>
+            where
+                T: Format<Pretty>,
+            {
+                fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+                    s.group(self).$dbg(f).entries(self).finish()
+                }
+            }
+        )*};
+    }
+    impl_list![
+        VecDeque dbg_list,
+        LinkedList dbg_list,
+        BinaryHeap dbg_list,
+        BTreeSet dbg_set,
+    ];
+
+    impl<K, V> Format<Debug> for BTreeMap<K, V>
+    where
+        K: Format<Debug>,
+        V: Format<Debug>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+            s.dbg_map(f).entries(self).finish()
+        }
+    }
+
+    impl<K, V> Format<Pretty> for BTreeMap<K, V>
+    where
+        K: Format<Pretty>,
+        V: Format<Pretty>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+            s.group(self).dbg_map(f).entries(self).finish()
+        }
+    }
+
     impl Write for String {
         fn write_str(&mut self, data: &str) -> Result {
             self.push_str(data);
@@ -405,9 +847,48 @@ mod with_alloc {
 
 #[cfg(any(feature = "std", test))]
 mod with_std {
-    use crate::fmt::{Result, Write};
+    use crate::fmt::{Debug, Format, Pretty, Result, Write};
+    use std::collections::{HashMap, HashSet};
     use std::ffi::OsString;
 
+    impl<T, H> Format<Debug> for HashSet<T, H>
+    where
+        T: Format<Debug>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+            s.dbg_set(f).entries(self).finish()
+        }
+    }
+
+    impl<T, H> Format<Pretty> for HashSet<T, H>
+    where
+        T: Format<Pretty>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+            s.group(self).dbg_set(f).entries(self).finish()
+        }
+    }
+
+    impl<K, V, H> Format<Debug> for HashMap<K, V, H>
+    where
+        K: Format<Debug>,
+        V: Format<Debug>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Debug) -> Result {
+            s.dbg_map(f).entries(self).finish()
+        }
+    }
+
+    impl<K, V, H> Format<Pretty> for HashMap<K, V, H>
+    where
+        K: Format<Pretty>,
+        V: Format<Pretty>,
+    {
+        fn fmt(&self, f: &mut dyn Write, s: &Pretty) -> Result {
+            s.group(self).dbg_map(f).entries(self).finish()
+        }
+    }
+
     impl Write for OsString {
         fn write_str(&mut self, data: &str) -> Result {
             self.push(data);
@@ -3,45 +3,37 @@
 /// Should not be used directly; rather, use through macros like [`write`] and [`format`].
 ///
 /// See [`restd::fmt`](crate::fmt) for details on the syntax.
+///
+/// With the `derive` feature, std-style format strings are also accepted
+/// when preceded by `std`, e.g. `format!(std "{x:>8?} {:#x}", 255)`. Each
+/// placeholder is mapped onto the equivalent styles (here,
+/// `Pad::right(' ', 8, Debug)` and `Prefix("0x", Hex(false).twos_complement())`).
+/// Widths and precisions can't be taken from arguments. Like std, numbers
+/// align right by default and anything else left, negative numbers are written
+/// in two's complement in hex, octal and binary, and a precision on anything
+/// but a number [truncates](crate::fmt::Truncate) it. The `+` flag only
+/// applies to numbers:
+///
+/// ```compile_fail
+/// let s = restd::format!(std "{:+}", "not a number");
+/// ```
+///
+/// Pieces may be preceded by bindings, written `name = expr;`. Each is
+/// evaluated exactly once, before any formatting, and can then be referenced
+/// by name (as a reference to the value) any number of times, e.g.
+/// `format!(n = expensive(); n, " = ", n as Hex::prefix(false))`.
 #[macro_export]
 macro_rules! format_args {
     () => {
         $crate::fmt::args::Arguments(&[])
     };
 
-    ($(
-        $(  $el_id:ident $( . $el_id_field:tt )* )?
-        $(  $el_li:literal )?
-        $({ $el_ex:expr   })?
-        $( as $style:expr )?
-    ),+ ,) => {
-        $crate::format_args!($(
-            $( $el_id $($el_id_field)* )?
-            $( $el_li )?
-            $( { $el_ex } )?
-            $( as $style )?
-        ),+)
+    (std $fmt:literal $($args:tt)*) => {
+        $crate::fmt::_std_format_args!($crate, false, $fmt $($args)*)
     };
 
-    ($(
-        $(  $el_id:ident $( . $el_id_field:tt )* )?
-        $(  $el_li:literal )?
-        $({ $el_ex:expr   })?
-        $( as $style:expr )?
-    ),+) => {
-        $crate::fmt::args::Arguments(&[$(
-            $crate::fmt::args::Var::new(
-                $(&$el_id $(.$el_id_field)*,)?
-                $(&$el_li,)?
-                $(&$el_ex,)?
-
-                $crate::_if_else!(
-                    [$( &$style )?]
-                    else
-                    [&$crate::fmt::Display]
-                )
-            ),
-        )*])
+    ($($t:tt)+) => {
+        $crate::_format_args!([] [] $($t)+)
     };
 }
 
@@ -56,13 +48,57 @@ macro_rules! format_args_nl {
         $crate::fmt::args::Arguments(&[])
     };
 
-    ($(
+    (std $fmt:literal $($args:tt)*) => {
+        $crate::fmt::_std_format_args!($crate, true, $fmt $($args)*)
+    };
+
+    ($($t:tt)+) => {
+        $crate::_format_args!(
+            []
+            [$crate::fmt::args::Var::new(&'\n', &$crate::fmt::Display)]
+            $($t)+
+        )
+    };
+}
+
+/// Collects the leading `name = expr;` bindings for [`format_args`], then
+/// builds the arguments from the remaining pieces.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _format_args {
+    ([$($binds:tt)*] [$($extra:expr)?] $name:ident = $val:expr; $($rest:tt)+) => {
+        $crate::_format_args!([$($binds)* ($name, $val)] [$($extra)?] $($rest)+)
+    };
+
+    ([] [$($extra:expr)?] $($pieces:tt)+) => {
+        $crate::fmt::args::Arguments(&$crate::_format_vars!([$($extra)?] $($pieces)+))
+    };
+
+    ([$(($name:ident, $val:expr))+] [$($extra:expr)?] $($pieces:tt)+) => {
+        $crate::fmt::args::Arguments(&[$crate::fmt::args::Var::new(
+            &$crate::fmt::args::Bindings::new(($(&$val,)+), |binds, f| {
+                let ($($name,)+) = *binds;
+                $crate::fmt::args::Arguments(
+                    &$crate::_format_vars!([$($extra)?] $($pieces)+)
+                ).write(f)
+            }),
+            &$crate::fmt::Display,
+        )])
+    };
+}
+
+/// Builds the array of [`Var`](crate::fmt::args::Var)s for [`format_args`],
+/// followed by any extra vars given in brackets.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _format_vars {
+    ([$($extra:expr)?] $(
         $(  $el_id:ident $( . $el_id_field:tt )* )?
         $(  $el_li:literal )?
         $({ $el_ex:expr   })?
         $( as $style:expr )?
     ),+ ,) => {
-        $crate::format_args_nl!($(
+        $crate::_format_vars!([$($extra)?] $(
             $( $el_id $($el_id_field)* )?
             $( $el_li )?
             $( { $el_ex } )?
@@ -70,13 +106,13 @@ macro_rules! format_args_nl {
         ),+)
     };
 
-    ($(
+    ([$($extra:expr)?] $(
         $(  $el_id:ident $( . $el_id_field:tt )* )?
         $(  $el_li:literal )?
         $({ $el_ex:expr   })?
         $( as $style:expr )?
     ),+) => {
-        $crate::fmt::args::Arguments(&[$(
+        [$(
             $crate::fmt::args::Var::new(
                 $(&$el_id $(.$el_id_field)*,)?
                 $(&$el_li,)?
@@ -88,9 +124,7 @@ macro_rules! format_args_nl {
                     [&$crate::fmt::Display]
                 )
             ),
-        )*
-            $crate::fmt::args::Var::new(&'\n', &$crate::fmt::Display),
-        ])
+        )* $($extra,)?]
     };
 }
 
@@ -186,7 +220,7 @@ mod with_std {
                         "] ",
                         { ::core::stringify!($x) },
                         " = ",
-                        x as $crate::fmt::Pretty(0)
+                        x as $crate::fmt::Pretty::new()
                     );
                     x
                 }
@@ -201,3 +235,11 @@ macro_rules! _if_else {
     ( [] else [$($t:tt)*] ) => { $($t)* };
     ( [$($t:tt)*] else [$($_:tt)*] ) => { $($t)* };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _no_std_format_args {
+    ($($_:tt)*) => {
+        ::core::compile_error!("std-style format strings require the `derive` feature")
+    };
+}
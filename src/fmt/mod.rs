@@ -5,17 +5,39 @@
 //! Redesign of [`std::fmt`](https:?/doc.rust-lang.org/std/fmt/index.html).
 
 mod adapters;
+mod any;
 pub mod args;
 mod binary;
+mod bits;
+mod bytes;
+mod combine;
 mod debug;
 mod derives;
 mod display;
+mod duration;
+mod dynamic;
+mod ext;
+mod fixed;
+mod float;
+mod group;
 mod hex;
 mod impls;
 mod macros;
+mod octal;
 mod pad;
 mod prefix;
 mod pretty;
+mod quote;
+mod radix;
+mod sci;
+mod sign;
+mod style;
+mod suffix;
+mod surround;
+pub mod template;
+mod truncate;
+mod width;
+mod wrap;
 
 #[cfg(test)]
 mod test;
@@ -24,14 +46,45 @@ mod test;
 use alloc::string::String;
 
 pub use adapters::{RestdWrite, StdDebug, StdDisplay, StdWrite};
+pub use any::{impl_any, AnyStyle, FormatAny};
 pub use binary::Binary;
+pub use bits::{Bits, Signed};
+pub use bytes::Bytes;
+pub use combine::{Both, Then};
 pub use debug::Debug;
 pub use derives::derive;
 pub use display::Display;
+pub use duration::HumanDuration;
+#[cfg(any(feature = "alloc", test))]
+pub use dynamic::BoxedFormat;
+pub use dynamic::{DynFormat, Styled};
+pub use ext::{Case, StyleExt};
+pub use fixed::Fixed;
+pub use group::Group;
 pub use hex::Hex;
+pub use octal::Octal;
 pub use pad::{Dir, Kind, Pad};
 pub use prefix::Prefix;
 pub use pretty::Pretty;
+pub use quote::{Delim, Quote};
+pub use radix::Radix;
+#[cfg(feature = "derive")]
+pub use restd_derive::{Debug, Pretty};
+
+#[cfg(not(feature = "derive"))]
+#[doc(hidden)]
+pub use crate::_no_std_format_args as _std_format_args;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use restd_derive::std_format_args as _std_format_args;
+pub use sci::Sci;
+pub use sign::Sign;
+pub use style::{Base, Combinator, Combined, Formats, Modified, Modifier, Role, Style};
+pub use suffix::Suffix;
+pub use surround::Surround;
+pub use truncate::Truncate;
+pub use width::{Measure, WidthCounter};
+pub use wrap::Wrap;
 
 /// The type returned by formatter methods.
 pub type Result = core::result::Result<(), Error>;
@@ -42,43 +95,14 @@ pub type Result = core::result::Result<(), Error>;
 ///
 /// Note that this carries no information: any extra info must be signaled
 /// out-of-band, e.g. by a field on the writer.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq, Hash)]
 pub struct Error;
 
-/// A specific way to format things, e.g. [`Display`] or [`Hex`], for use by a
-/// [`Format`].
-///
-/// Some styles (like `Display`) are merely markers; some, like [`Debug`](struct@Debug), have
-/// methods on them to aid in using them; and some, like `Hex`, carry additional
-/// information inside about how things should be formatted.
-pub trait Style {}
-
-/// A wrapper around a style, providing a modification to that style; for
-/// example, [`Prefix`] merely prints something before something else.
-pub trait Modifier: Style {
-    /// The style wrapped by this modifier.
-    type Inner: Style;
-
-    /// Apply this modifier to any type.
-    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
-    where
-        T: Format<Self::Inner> + ?Sized;
-}
-
-// TODO: can any form of this work?
-// impl<T, M> Format<M> for T
-// where
-//     T: Format<M::Inner>,
-//     M: Modifier,
-// {
-//     fn fmt(&self, f: &mut dyn Write, style: &M) -> Result {
-//         style.apply(f, self)
-//     }
-// }
-
 /// A type that can be formatted in a specific [style](Style).
 ///
-/// All types implementing this should also use [`stylable`](crate::stylable).
+/// Only needs implementing for base styles: any [`Modifier`] or
+/// [`Combinator`] wrapping a style applies automatically. Generic code
+/// accepting any style should use a [`Formats`] bound on the style instead.
 ///
 /// A more general form of traits like `Display` and `Debug` in std; the
 /// equivalent forms would be <code>Format<[Display]></code> and
@@ -96,8 +120,20 @@ pub trait Format<S: Style> {
     }
 }
 
-/// A trait for writing or formatting into Unicode-accepting buffers or streams.
+/// Converts `data` into a `String` in any style that [formats](Formats) it,
+/// including modifiers; e.g. `stringify(&x, &Pad::left(' ', 5, Display))`.
+#[cfg(any(feature = "alloc", test))]
+pub fn stringify<T, S>(data: &T, style: &S) -> String
+where
+    T: ?Sized,
+    S: Formats<T>,
+{
+    let mut f = String::new();
+    style.format(&mut f, data).unwrap();
+    f
+}
 
+/// A trait for writing or formatting into Unicode-accepting buffers or streams.
 /// This trait only accepts UTF-8–encoded data and is not flushable. If you only
 /// want to accept Unicode and you don’t need flushing, you should implement
 /// this trait; otherwise you should implement [`io::Write`](crate::io::Write).
@@ -141,45 +177,16 @@ impl<W: Write + ?Sized> Write for &mut W {
     }
 }
 
-/// Use this on any and all types implementing any kind of `Format`. This allows
-/// a given type to be styled with any modifier.
-///
-/// Can either be used as `stylable!(Type)`, or
-/// `stylable!(for('a, T) Type<'a, T>)` (no trailing commas!).
+/// Previously needed on all types implementing `Format` to allow them to be
+/// styled with modifiers. Modifiers now apply automatically, so this expands to
+/// nothing, and is only kept for compatibility.
 ///
-/// Automatically applied with [`derive`].
+/// Since types no longer implement `Format` for modifiers, use [`stringify()`]
+/// for them instead, e.g. `stringify(&x, &Pad::left(' ', 5, Display))` rather
+/// than `x.stringify(&Pad::left(' ', 5, Display))`.
 #[macro_export]
 macro_rules! stylable {
-    (for($($gen:tt)*) $($typ:tt)*) => {
-        impl<
-            $($gen)*,
-            __StylableModifier,
-        > $crate::fmt::Format<__StylableModifier> for $($typ)*
-        where
-            $($typ)*: $crate::fmt::Format<__StylableModifier::Inner>,
-            __StylableModifier: $crate::fmt::Modifier,
-        {
-            fn fmt(
-                &self,
-                f: &mut dyn $crate::fmt::Write,
-                style: &__StylableModifier,
-            ) -> $crate::fmt::Result {
-                style.apply(f, self)
-            }
-        }
-    };
-
-    ($($typ:ty),+ $(,)?) => {$(
-        impl<M> $crate::fmt::Format<M> for $typ
-        where
-            $typ: $crate::fmt::Format<M::Inner>,
-            M: $crate::fmt::Modifier,
-        {
-            fn fmt(&self, f: &mut dyn $crate::fmt::Write, style: &M) -> $crate::fmt::Result {
-                style.apply(f, self)
-            }
-        }
-    )+};
+    ($($_:tt)*) => {};
 }
 
 #[doc(hidden)]
@@ -0,0 +1,28 @@
+use super::Prefix;
+
+/// A repr for formatting data in octal. Implemented for all integers.
+///
+/// Negative numbers are written as a `-` followed by their magnitude; see
+/// [`Bits`](super::Bits) for two's complement and full-width output.
+///
+/// Unlike [`Debug`](super::Debug) and [`Pretty`](super::Pretty), cannot be
+/// derived, since most types cannot be intuitively formatted in octal.
+///
+/// Equivalent of [`core::fmt::Octal`].
+#[derive(Default, Clone, Copy)]
+pub struct Octal;
+impl super::Style for Octal {
+    type Role = super::Base;
+
+    fn radix(&self) -> u32 {
+        8
+    }
+}
+super::derive!(struct Octal);
+
+impl Octal {
+    /// Create a new `Octal`, with the prefix `0o` automatically applied.
+    pub fn prefix() -> Prefix<&'static str, Self> {
+        Prefix("0o", Self)
+    }
+}
@@ -1,5 +1,7 @@
-use super::{Format, Modifier, Result, Style, Write};
-use crate::io::Counter;
+use super::{
+    Display, Error, Format, Formats, Measure, Modified, Modifier, Result, Style, WidthCounter,
+    Write,
+};
 
 /// The direction to place the text in when padding.
 #[allow(missing_docs)]
@@ -8,12 +10,17 @@ pub enum Dir {
     Left,
     Center,
     Right,
+
+    /// Like `Right`, but places the padding after a leading sign (`+` or
+    /// `-`), if there is one; e.g. `-0005` rather than `000-5`.
+    Sign,
 }
 super::derive!(
     enum Dir {
         Left,
         Center,
         Right,
+        Sign,
     }
 );
 
@@ -35,15 +42,22 @@ super::derive!(
     }
 );
 
-// TODO: should this support arbitrary str padding?
-/// Pad any data with a given character until it reaches a certain width.
+/// Pad any data with a given fill until it reaches a certain width.
+///
+/// The fill is usually a `char`, but may be anything that implements
+/// <code>[Format]\<[Display]></code>; e.g. `". "` for the leaders in a table
+/// of contents. Patterns are repeated from their start in each run of
+/// padding, with the last repetition cut short if it doesn't fit.
+///
+/// The data is only formatted once, unless padding comes before it and it's
+/// longer than 128 bytes, in which case it's measured and then formatted again.
 #[derive(Clone, Copy, PartialEq, Eq)]
-pub struct Pad<S> {
+pub struct Pad<S, F = char> {
     /// The direction to place the text in when padding.
     pub align: Dir,
 
-    /// The character to pad with.
-    pub with: char,
+    /// What to pad with.
+    pub with: F,
 
     /// The width to target when padding.
     pub count: usize,
@@ -51,135 +65,345 @@ pub struct Pad<S> {
     /// The kind of padding.
     pub kind: Kind,
 
+    /// How to measure the width of the data.
+    pub measure: Measure,
+
     /// The style being wrapped by this modifier.
     pub style: S,
 }
-super::derive!(struct Pad<S!> { align, with, count, kind, style });
+super::derive!(struct Pad<S!, F!> { align, with, count, kind, measure, style });
 
-impl<S: Style> Pad<S> {
+impl<S: Style, F: Format<Display>> Pad<S, F> {
     /// Pad left.
     ///
-    /// Shorthand for `Pad { dir: Dir::Left, with, count, kind: Full, style }`
-    pub const fn left(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Left, with, count, kind: Full, measure: Chars, style }`
+    pub const fn left(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Left,
             with,
             count,
             kind: Kind::Full,
+            measure: Measure::Chars,
             style,
         }
     }
 
     /// Pad to the center.
     ///
-    /// Shorthand for `Pad { dir: Dir::Center, with, count, kind: Full, style }`
-    pub const fn center(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Center, with, count, kind: Full, measure: Chars, style }`
+    pub const fn center(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Center,
             with,
             count,
             kind: Kind::Full,
+            measure: Measure::Chars,
             style,
         }
     }
 
     /// Pad right.
     ///
-    /// Shorthand for `Pad { dir: Dir::Right, with, count, kind: Full, style }`
-    pub const fn right(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Right, with, count, kind: Full, measure: Chars, style }`
+    pub const fn right(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Right,
             with,
             count,
             kind: Kind::Full,
+            measure: Measure::Chars,
             style,
         }
     }
 
     /// Pad left using [modulo padding](Kind::Mod).
     ///
-    /// Shorthand for `Pad { dir: Dir::Left, with, count, kind: Mod, style }`
-    pub const fn left_mod(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Left, with, count, kind: Mod, measure: Chars, style }`
+    pub const fn left_mod(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Left,
             with,
             count,
             kind: Kind::Mod,
+            measure: Measure::Chars,
             style,
         }
     }
 
     /// Pad to the center using [modulo padding](Kind::Mod).
     ///
-    /// Shorthand for `Pad { dir: Dir::Center, with, count, kind: Mod, style }`
-    pub const fn center_mod(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Center, with, count, kind: Mod, measure: Chars, style }`
+    pub const fn center_mod(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Center,
             with,
             count,
             kind: Kind::Mod,
+            measure: Measure::Chars,
             style,
         }
     }
 
     /// Pad right using [modulo padding](Kind::Mod).
     ///
-    /// Shorthand for `Pad { dir: Dir::Right, with, count, kind: Mod, style }`
-    pub const fn right_mod(with: char, count: usize, style: S) -> Self {
+    /// Shorthand for `Pad { dir: Dir::Right, with, count, kind: Mod, measure: Chars, style }`
+    pub const fn right_mod(with: F, count: usize, style: S) -> Self {
         Self {
             align: Dir::Right,
             with,
             count,
             kind: Kind::Mod,
+            measure: Measure::Chars,
             style,
         }
     }
 }
 
-impl<S: Style> Style for Pad<S> {}
+impl<S, F> Pad<S, F> {
+    /// Measure the width of the data using `measure`, e.g. to align text
+    /// containing emoji or East Asian characters.
+    pub fn measured(self, measure: Measure) -> Self {
+        Self { measure, ..self }
+    }
+}
+
+impl<S: Style, F: Format<Display>> Style for Pad<S, F> {
+    type Role = Modified;
 
-impl<S: Style> Modifier for Pad<S> {
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+
+impl<S: Style, F: Format<Display>> Modifier for Pad<S, F> {
     type Inner = S;
 
     fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
     where
-        T: Format<S> + ?Sized,
+        T: ?Sized,
+        S: Formats<T>,
     {
-        let mut counter = Counter::new();
-        data.fmt(&mut counter, &self.style)?;
+        let fill = Fill::new(&self.with, self.measure)?;
+        let chs = |width: usize| match self.kind {
+            Kind::Full => self.count.saturating_sub(width),
+            Kind::Mod => self.count - width.checked_rem(self.count).unwrap_or(0),
+        };
 
-        let chs = match self.kind {
-            Kind::Full => self.count.saturating_sub(counter.0),
-            Kind::Mod => self.count - counter.0.checked_rem(self.count).unwrap_or(0),
+        // the padding comes last, so the data can be measured as it's written
+        if self.align == Dir::Left {
+            let mut f = Tee {
+                f,
+                counter: WidthCounter::new(self.measure),
+            };
+            self.style.format(&mut f, data)?;
+
+            return fill.write(f.f, chs(f.counter.width));
+        }
+
+        let mut buf = Buffer {
+            counter: WidthCounter::new(self.measure),
+            buf: [0; BUFFER_LEN],
+            len: 0,
+            overflowed: false,
+        };
+        self.style.format(&mut buf, data)?;
+        let chs = chs(buf.counter.width);
+
+        // only format the data again if it didn't fit in the buffer
+        let write = |f: &mut dyn Write| match buf.as_str()? {
+            Some(data) => f.write_str(data),
+            None => self.style.format(f, data),
         };
 
         match self.align {
-            Dir::Left => {
-                data.fmt(f, &self.style)?;
-                for _ in 0..chs {
-                    f.write_char(self.with)?;
-                }
-            }
+            Dir::Left => unreachable!("streamed above"),
             Dir::Center => {
-                let before = chs / 2;
-                let after = chs.div_ceil(2);
+                fill.write(f, chs / 2)?;
+                write(f)?;
+                fill.write(f, chs.div_ceil(2))?;
+            }
+            Dir::Right => {
+                fill.write(f, chs)?;
+                write(f)?;
+            }
+            Dir::Sign => {
+                let mut f = AfterSign {
+                    f,
+                    fill: &fill,
+                    chs: Some(chs),
+                };
+                write(&mut f)?;
 
-                for _ in 0..before {
-                    f.write_char(self.with)?;
-                }
-                data.fmt(f, &self.style)?;
-                for _ in 0..after {
-                    f.write_char(self.with)?;
+                // nothing was written, so the padding hasn't been either
+                if let Some(chs) = f.chs {
+                    fill.write(f.f, chs)?;
                 }
             }
-            Dir::Right => {
-                for _ in 0..chs {
-                    f.write_char(self.with)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The size of the buffer used to avoid formatting data twice when padding
+/// comes before it.
+const BUFFER_LEN: usize = 128;
+
+/// Measures data, storing it if it fits.
+struct Buffer {
+    counter: WidthCounter,
+    buf: [u8; BUFFER_LEN],
+    len: usize,
+    overflowed: bool,
+}
+
+impl Buffer {
+    /// Returns the buffered data, if it all fit.
+    fn as_str(&self) -> core::result::Result<Option<&str>, Error> {
+        if self.overflowed {
+            return Ok(None);
+        }
+
+        core::str::from_utf8(&self.buf[..self.len])
+            .map(Some)
+            .map_err(|_| Error)
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, data: &str) -> Result {
+        self.counter.write_str(data)?;
+
+        if !self.overflowed {
+            match self.buf.get_mut(self.len..self.len + data.len()) {
+                Some(buf) => {
+                    buf.copy_from_slice(data.as_bytes());
+                    self.len += data.len();
                 }
-                data.fmt(f, &self.style)?;
+                None => self.overflowed = true,
             }
         }
 
         Ok(())
     }
 }
+
+/// Measures data while writing it.
+struct Tee<'w> {
+    f: &'w mut dyn Write,
+    counter: WidthCounter,
+}
+
+impl Write for Tee<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        self.counter.write_str(data)?;
+        self.f.write_str(data)
+    }
+
+    fn write_char(&mut self, ch: char) -> Result {
+        self.counter.write_char(ch)?;
+        self.f.write_char(ch)
+    }
+}
+
+/// A fill pattern, along with its width.
+struct Fill<'p, F> {
+    with: &'p F,
+    width: usize,
+    measure: Measure,
+}
+
+impl<'p, F: Format<Display>> Fill<'p, F> {
+    fn new(with: &'p F, measure: Measure) -> core::result::Result<Self, Error> {
+        let mut counter = WidthCounter::new(measure);
+        with.fmt(&mut counter, &Display)?;
+
+        Ok(Self {
+            with,
+            width: counter.width,
+            measure,
+        })
+    }
+
+    /// Write `chs` worth of the pattern, cutting the last repetition short if
+    /// necessary.
+    fn write(&self, f: &mut dyn Write, mut chs: usize) -> Result {
+        if self.width == 0 {
+            return Ok(());
+        }
+
+        while chs >= self.width {
+            self.with.fmt(f, &Display)?;
+            chs -= self.width;
+        }
+
+        if chs == 0 {
+            return Ok(());
+        }
+
+        let mut f = Partial {
+            f,
+            counter: WidthCounter::new(self.measure),
+            left: chs,
+            full: false,
+        };
+        match self.with.fmt(&mut f, &Display) {
+            Err(_) if f.full => Ok(()),
+            res => res,
+        }
+    }
+}
+
+/// Writes up to `left` worth of data, then stops.
+struct Partial<'w> {
+    f: &'w mut dyn Write,
+    counter: WidthCounter,
+    left: usize,
+    full: bool,
+}
+
+impl Write for Partial<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        for ch in data.chars() {
+            self.write_char(ch)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> Result {
+        self.counter.push(ch);
+        if self.counter.width > self.left {
+            self.full = true;
+            return Err(Error);
+        }
+
+        self.f.write_char(ch)
+    }
+}
+
+/// Writes padding after the leading sign, if any, of the data.
+struct AfterSign<'w, 'p, F> {
+    f: &'w mut dyn Write,
+    fill: &'p Fill<'p, F>,
+    chs: Option<usize>,
+}
+
+impl<F: Format<Display>> Write for AfterSign<'_, '_, F> {
+    fn write_str(&mut self, mut data: &str) -> Result {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(chs) = self.chs.take() {
+            if let Some(rest) = data.strip_prefix(['+', '-']) {
+                self.f.write_str(&data[..1])?;
+                data = rest;
+            }
+
+            self.fill.write(self.f, chs)?;
+        }
+
+        self.f.write_str(data)
+    }
+}
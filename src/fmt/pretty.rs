@@ -1,97 +1,245 @@
-use super::{Format, Result, Style, Write};
+use super::{Base, Error, Format, Formats, Result, Style, Write};
 
 /// A pretty-printing representation, like [`Debug`](super::Debug) but
 /// emphasizing readability (e.g. spread over multiple lines).
 ///
-/// The inner `usize` represents how many layers of indentation deep it
-/// currently is.
+/// By default, every tuple, struct, and collection is spread over multiple
+/// lines. If given a [`width`](Pretty::width), each of those is instead kept on
+/// one line if it fits, and only broken up otherwise.
 ///
 /// Can be derived via [`derive`](crate::fmt::derive).
 ///
 /// Equivalent to the alternate (`#`) form of [`core::fmt::Debug`].
-#[derive(Default, Clone, Copy)]
-pub struct Pretty(pub usize);
-impl Style for Pretty {}
-super::derive!(struct Pretty(depth));
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Pretty {
+    /// How many layers of indentation deep formatting currently is.
+    pub depth: usize,
+
+    /// The string written for each layer of indentation.
+    pub indent: &'static str,
+
+    /// The line width to fit groups into, if any.
+    pub width: Option<usize>,
+
+    /// Whether or not the current group is being laid out on one line.
+    pub flat: bool,
+
+    /// The column at which the current value starts.
+    pub offset: usize,
+}
+impl Style for Pretty {
+    type Role = Base;
+}
+super::derive!(struct Pretty { depth, indent, width, flat, offset });
+
+impl Default for Pretty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Pretty {
-    /// Creates a new `Pretty` at 0 indentation. Equivalent to `Pretty(0)`.
+    /// Creates a new `Pretty` at 0 indentation, indenting with four spaces,
+    /// and always spreading groups over multiple lines.
     pub const fn new() -> Self {
-        Self(0)
+        Self {
+            depth: 0,
+            indent: "    ",
+            width: None,
+            flat: false,
+            offset: 0,
+        }
+    }
+
+    /// Keep groups on one line when they fit within `width` columns.
+    pub const fn width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// Use `indent` for each layer of indentation.
+    pub const fn indent(self, indent: &'static str) -> Self {
+        Self { indent, ..self }
+    }
+
+    /// Decides how to lay out `data`, returning the style to format it with.
+    ///
+    /// If `data` fits on the rest of the line, the returned style is
+    /// [`flat`](Pretty::flat), otherwise it is unchanged. Types formatting
+    /// themselves via the helpers (e.g. [`dbg_struct`](Pretty::dbg_struct))
+    /// should call this first.
+    pub fn group<T: Format<Pretty> + ?Sized>(&self, data: &T) -> Self {
+        let Some(width) = self.width else {
+            return *self;
+        };
+
+        if self.flat {
+            return *self;
+        }
+
+        // leave room for the trailing comma when nested
+        let mut fits = Fits {
+            left: width.saturating_sub(self.offset + (self.depth > 0) as usize),
+        };
+        let flat = Self {
+            flat: true,
+            ..*self
+        };
+
+        if data.fmt(&mut fits, &flat).is_ok() {
+            flat
+        } else {
+            *self
+        }
     }
 
     /// Assists with formatting tuple structs (or tuples, when `name` is empty).
     pub fn dbg_tuple<'w>(&self, f: &'w mut dyn Write, name: &str) -> PrettyTuple<'w> {
-        PrettyTuple::new(f, name, self.0)
+        PrettyTuple::new(f, name, *self)
     }
 
     /// Assists with formatting structs (or objects, when `name` is empty).
     pub fn dbg_struct<'w>(&self, f: &'w mut dyn Write, name: &str) -> PrettyStruct<'w> {
-        PrettyStruct::new(f, name, self.0)
+        PrettyStruct::new(f, name, *self)
+    }
+
+    /// Assists with formatting lists, e.g. `[1, 2, 3]`.
+    pub fn dbg_list<'w>(&self, f: &'w mut dyn Write) -> PrettyList<'w> {
+        PrettyList::new(f, *self)
+    }
+
+    /// Assists with formatting sets, e.g. `{1, 2, 3}`.
+    pub fn dbg_set<'w>(&self, f: &'w mut dyn Write) -> PrettyList<'w> {
+        PrettyList::set(f, *self)
+    }
+
+    /// Assists with formatting maps, e.g. `{"a": 1, "b": 2}`.
+    pub fn dbg_map<'w>(&self, f: &'w mut dyn Write) -> PrettyMap<'w> {
+        PrettyMap::new(f, *self)
     }
 }
 
-/// A helper for formatting tuples and tuple structs.
+/// Fails once more than `left` characters, or any newline, are written.
+struct Fits {
+    left: usize,
+}
+
+impl Write for Fits {
+    fn write_str(&mut self, data: &str) -> Result {
+        let len = data.chars().count();
+        if len > self.left || data.contains('\n') {
+            return Err(Error);
+        }
+
+        self.left -= len;
+        Ok(())
+    }
+}
+
+/// Tracks the column of everything written through it.
+struct Column<'w> {
+    f: &'w mut dyn Write,
+    col: usize,
+}
+
+impl Write for Column<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        match data.rsplit_once('\n') {
+            Some((_, line)) => self.col = line.chars().count(),
+            None => self.col += data.chars().count(),
+        }
+
+        self.f.write_str(data)
+    }
+}
+
+/// The layout shared by all the helpers: a sequence of entries, either on one
+/// line separated by commas, or on their own lines with trailing commas.
 ///
 /// If an error is encountered, any future calls will no-op.
-pub struct PrettyTuple<'w> {
+struct Seq<'w> {
     f: &'w mut dyn Write,
-    depth: usize,
+    style: Pretty,
+    entries: usize,
     err: Result,
 }
 
-impl<'w> PrettyTuple<'w> {
-    /// Create a new `PrettyTuple`. Leave `name` empty for formatting tuples.
-    pub fn new(f: &'w mut dyn Write, name: &str, depth: usize) -> Self {
-        let err = f.write_str(name).and_then(|_| f.write_str("("));
+impl<'w> Seq<'w> {
+    fn new(f: &'w mut dyn Write, style: Pretty, name: &str, open: &str) -> Self {
+        let err = f.write_str(name).and_then(|_| f.write_str(open));
 
-        Self { f, err, depth }
+        Self {
+            f,
+            style,
+            entries: 0,
+            err,
+        }
     }
 
-    /// Format a field using [`Pretty`].
-    pub fn field<T: Format<Pretty>>(&mut self, data: &T) -> &mut Self {
-        let depth = self.depth + 1;
-        self.field_with(|f| data.fmt(f, &Pretty(depth)))
+    /// The style to format an entry with, given the width of whatever
+    /// precedes it on its line (e.g. a field name).
+    fn child(&self, prefix: usize) -> Pretty {
+        let depth = self.style.depth + 1;
+        Pretty {
+            depth,
+            offset: depth * self.style.indent.chars().count() + prefix,
+            ..self.style
+        }
     }
 
-    /// Format a field using a given style.
-    pub fn field_styled<T: Format<S>, S: Style>(&mut self, data: &T, style: &S) -> &mut Self {
-        self.field_with(|f| data.fmt(f, style))
+    /// Starts an entry. `pad` adds a space after the opening delimiter when
+    /// on one line, e.g. `Foo { x: 1 }`.
+    fn start(&mut self, pad: bool) {
+        if self.style.flat {
+            if self.entries != 0 {
+                self.with_err(|f| f.write_str(", "));
+            } else if pad {
+                self.with_err(|f| f.write_char(' '));
+            }
+        } else {
+            self.newline(self.style.depth + 1);
+        }
     }
 
-    /// Format a field using a given closure instead of data.
-    pub fn field_with(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) -> &mut Self {
-        self.newline(self.depth + 1);
-        self.with_err(f);
-        self.with_err(|f| f.write_char(','));
-        self
+    /// Ends an entry.
+    fn end(&mut self) {
+        if !self.style.flat {
+            self.with_err(|f| f.write_char(','));
+        }
+
+        self.entries += 1;
     }
 
-    /// Finish off the tuple (struct), returning an error if any were
-    /// encountered.
-    pub fn finish(&mut self) -> Result {
-        self.newline(self.depth);
-        self.with_err(|f| f.write_char(')'));
+    /// Finishes off the sequence. `pad` adds a space before the closing
+    /// delimiter when on one line and non-empty.
+    fn finish(&mut self, pad: bool, close: &str) -> Result {
+        if !self.style.flat {
+            self.newline(self.style.depth);
+        } else if pad && self.entries != 0 {
+            self.with_err(|f| f.write_char(' '));
+        }
+
+        self.with_err(|f| f.write_str(close));
         self.err
     }
 
-    /// Finish off the tuple (struct), with a trailing `...` field, returning an
-    /// error if any were encountered.
-    pub fn non_exhaustive(&mut self) -> Result {
-        self.newline(self.depth + 1);
+    /// Finishes off the sequence with a trailing `...` entry.
+    fn non_exhaustive(&mut self, pad: bool, close: &str) -> Result {
+        self.start(pad);
         self.with_err(|f| f.write_str("..."));
-        self.newline(self.depth);
-        self.with_err(|f| f.write_char(')'));
-
-        self.err
+        self.entries += 1;
+        self.finish(pad, close)
     }
 
     /// Writes a newline, plus `depth` layers of indentation.
     fn newline(&mut self, depth: usize) {
+        let indent = self.style.indent;
         self.with_err(|f| {
             f.write_char('\n')?;
             for _ in 0..depth {
-                f.write_str("    ")?;
+                f.write_str(indent)?;
             }
             Ok(())
         });
@@ -104,37 +252,91 @@ impl<'w> PrettyTuple<'w> {
     }
 }
 
+/// A helper for formatting tuples and tuple structs.
+///
+/// If an error is encountered, any future calls will no-op.
+pub struct PrettyTuple<'w> {
+    seq: Seq<'w>,
+    tuple: bool,
+}
+
+impl<'w> PrettyTuple<'w> {
+    /// Create a new `PrettyTuple`. Leave `name` empty for formatting tuples.
+    pub fn new(f: &'w mut dyn Write, name: &str, style: Pretty) -> Self {
+        Self {
+            seq: Seq::new(f, style, name, "("),
+            tuple: name.is_empty(),
+        }
+    }
+
+    /// Format a field using [`Pretty`].
+    pub fn field<T: Format<Pretty> + ?Sized>(&mut self, data: &T) -> &mut Self {
+        let style = self.seq.child(0);
+        self.field_with(|f| data.fmt(f, &style))
+    }
+
+    /// Format a field using a given style.
+    pub fn field_styled<T: ?Sized, S: Formats<T>>(&mut self, data: &T, style: &S) -> &mut Self {
+        self.field_with(|f| style.format(f, data))
+    }
+
+    /// Format a field using a given closure instead of data.
+    pub fn field_with(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) -> &mut Self {
+        self.seq.start(false);
+        self.seq.with_err(f);
+        self.seq.end();
+        self
+    }
+
+    /// Finish off the tuple (struct), returning an error if any were
+    /// encountered.
+    ///
+    /// On one line, tuples with a single field get a trailing comma, e.g.
+    /// `(1,)`.
+    pub fn finish(&mut self) -> Result {
+        if self.seq.style.flat && self.tuple && self.seq.entries == 1 {
+            self.seq.with_err(|f| f.write_char(','));
+        }
+
+        self.seq.finish(false, ")")
+    }
+
+    /// Finish off the tuple (struct), with a trailing `...` field, returning an
+    /// error if any were encountered.
+    pub fn non_exhaustive(&mut self) -> Result {
+        self.seq.non_exhaustive(false, ")")
+    }
+}
+
 /// A helper for formatting structs and objects.
 ///
 /// If an error is encountered, any future calls will no-op.
 pub struct PrettyStruct<'w> {
-    f: &'w mut dyn Write,
-    depth: usize,
-    err: Result,
+    seq: Seq<'w>,
 }
 
 impl<'w> PrettyStruct<'w> {
-    /// Create a new `DebugStruct`. Leave `name` empty for formatting objects.
-    pub fn new(f: &'w mut dyn Write, name: &str, depth: usize) -> Self {
-        let err = f.write_str(name).and_then(|_| f.write_str(" {"));
-
-        Self { f, depth, err }
+    /// Create a new `PrettyStruct`. Leave `name` empty for formatting objects.
+    pub fn new(f: &'w mut dyn Write, name: &str, style: Pretty) -> Self {
+        Self {
+            seq: Seq::new(f, style, name, " {"),
+        }
     }
 
     /// Format a field using [`Pretty`].
-    pub fn field<T: Format<Pretty>>(&mut self, name: &str, data: &T) -> &mut Self {
-        let depth = self.depth + 1;
-        self.field_with(name, |f| data.fmt(f, &Pretty(depth)))
+    pub fn field<T: Format<Pretty> + ?Sized>(&mut self, name: &str, data: &T) -> &mut Self {
+        let style = self.seq.child(name.chars().count() + 2);
+        self.field_with(name, |f| data.fmt(f, &style))
     }
 
     /// Format a field using a given style.
-    pub fn field_styled<T: Format<S>, S: Style>(
+    pub fn field_styled<T: ?Sized, S: Formats<T>>(
         &mut self,
         name: &str,
         data: &T,
         style: &S,
     ) -> &mut Self {
-        self.field_with(name, |f| data.fmt(f, style))
+        self.field_with(name, |f| style.format(f, data))
     }
 
     /// Format a field using a given closure instead of data.
@@ -143,10 +345,11 @@ impl<'w> PrettyStruct<'w> {
         name: &str,
         f: impl FnOnce(&mut dyn Write) -> Result,
     ) -> &mut Self {
-        self.newline(self.depth + 1);
-        self.with_err(|f| f.write_str(name).and_then(|_| f.write_str(": ")));
-        self.with_err(f);
-        self.with_err(|f| f.write_char(','));
+        self.seq.start(true);
+        self.seq
+            .with_err(|f| f.write_str(name).and_then(|_| f.write_str(": ")));
+        self.seq.with_err(f);
+        self.seq.end();
 
         self
     }
@@ -154,35 +357,160 @@ impl<'w> PrettyStruct<'w> {
     /// Finish off the struct (or object), returning an error if any were
     /// encountered.
     pub fn finish(&mut self) -> Result {
-        self.newline(self.depth);
-        self.with_err(|f| f.write_char('}'));
-        self.err
+        self.seq.finish(true, "}")
     }
 
     /// Finish off the struct (or object), with a trailing `...` field,
     /// returning an error if any were encountered.
     pub fn non_exhaustive(&mut self) -> Result {
-        self.newline(self.depth + 1);
-        self.with_err(|f| f.write_str("..."));
-        self.newline(self.depth);
-        self.with_err(|f| f.write_char('}'));
-        self.err
+        self.seq.non_exhaustive(true, "}")
     }
+}
 
-    /// Writes a newline, plus `depth` layers of indentation.
-    fn newline(&mut self, depth: usize) {
-        self.with_err(|f| {
-            f.write_char('\n')?;
-            for _ in 0..depth {
-                f.write_str("    ")?;
-            }
-            Ok(())
+/// A helper for formatting lists and sets.
+///
+/// If an error is encountered, any future calls will no-op.
+pub struct PrettyList<'w> {
+    seq: Seq<'w>,
+    close: &'static str,
+}
+
+impl<'w> PrettyList<'w> {
+    /// Create a new `PrettyList`, surrounded by square brackets.
+    pub fn new(f: &'w mut dyn Write, style: Pretty) -> Self {
+        Self {
+            seq: Seq::new(f, style, "", "["),
+            close: "]",
+        }
+    }
+
+    /// Create a new `PrettyList` for a set, surrounded by curly braces.
+    pub fn set(f: &'w mut dyn Write, style: Pretty) -> Self {
+        Self {
+            seq: Seq::new(f, style, "", "{"),
+            close: "}",
+        }
+    }
+
+    /// Format an entry using [`Pretty`].
+    pub fn entry<T: Format<Pretty> + ?Sized>(&mut self, data: &T) -> &mut Self {
+        let style = self.seq.child(0);
+        self.entry_with(|f| data.fmt(f, &style))
+    }
+
+    /// Format an entry using a given style.
+    pub fn entry_styled<T: ?Sized, S: Formats<T>>(&mut self, data: &T, style: &S) -> &mut Self {
+        self.entry_with(|f| style.format(f, data))
+    }
+
+    /// Format an entry using a given closure instead of data.
+    pub fn entry_with(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) -> &mut Self {
+        self.seq.start(false);
+        self.seq.with_err(f);
+        self.seq.end();
+        self
+    }
+
+    /// Format every item of an iterator as an entry using [`Pretty`].
+    pub fn entries<T: Format<Pretty>>(&mut self, iter: impl IntoIterator<Item = T>) -> &mut Self {
+        for data in iter {
+            self.entry(&data);
+        }
+
+        self
+    }
+
+    /// Finish off the list (or set), returning an error if any were
+    /// encountered.
+    pub fn finish(&mut self) -> Result {
+        self.seq.finish(false, self.close)
+    }
+
+    /// Finish off the list (or set), with a trailing `...` entry, returning an
+    /// error if any were encountered.
+    pub fn non_exhaustive(&mut self) -> Result {
+        self.seq.non_exhaustive(false, self.close)
+    }
+}
+
+/// A helper for formatting maps.
+///
+/// If an error is encountered, any future calls will no-op.
+pub struct PrettyMap<'w> {
+    seq: Seq<'w>,
+}
+
+impl<'w> PrettyMap<'w> {
+    /// Create a new `PrettyMap`.
+    pub fn new(f: &'w mut dyn Write, style: Pretty) -> Self {
+        Self {
+            seq: Seq::new(f, style, "", "{"),
+        }
+    }
+
+    /// Format an entry using [`Pretty`].
+    pub fn entry<K, V>(&mut self, key: &K, value: &V) -> &mut Self
+    where
+        K: Format<Pretty> + ?Sized,
+        V: Format<Pretty> + ?Sized,
+    {
+        let key_style = self.seq.child(0);
+        self.seq.start(false);
+
+        // the value starts wherever the key ends
+        let mut col = key_style.offset;
+        self.seq.with_err(|f| {
+            let mut f = Column { f, col };
+            key.fmt(&mut f, &key_style)?;
+            col = f.col;
+            f.write_str(": ")
         });
+
+        let value_style = Pretty {
+            offset: col + 2,
+            ..key_style
+        };
+        self.seq.with_err(|f| value.fmt(f, &value_style));
+        self.seq.end();
+
+        self
     }
 
-    /// Run a closure with the writer, if no errors have already been
-    /// encountered, updating `self.err`.
-    fn with_err(&mut self, f: impl FnOnce(&mut dyn Write) -> Result) {
-        self.err = self.err.and_then(|_| f(self.f));
+    /// Format an entry using given closures instead of data.
+    pub fn entry_with(
+        &mut self,
+        key: impl FnOnce(&mut dyn Write) -> Result,
+        value: impl FnOnce(&mut dyn Write) -> Result,
+    ) -> &mut Self {
+        self.seq.start(false);
+        self.seq.with_err(key);
+        self.seq.with_err(|f| f.write_str(": "));
+        self.seq.with_err(value);
+        self.seq.end();
+        self
+    }
+
+    /// Format every key-value pair of an iterator as an entry using [`Pretty`].
+    pub fn entries<K, V>(&mut self, iter: impl IntoIterator<Item = (K, V)>) -> &mut Self
+    where
+        K: Format<Pretty>,
+        V: Format<Pretty>,
+    {
+        for (key, value) in iter {
+            self.entry(&key, &value);
+        }
+
+        self
+    }
+
+    /// Finish off the map, returning an error if any were encountered.
+    pub fn finish(&mut self) -> Result {
+        self.seq.finish(false, "}")
+    }
+
+    /// Finish off the map, with a trailing `...` entry, returning an error if
+    /// any were encountered.
+    pub fn non_exhaustive(&mut self) -> Result {
+        self.seq.non_exhaustive(false, "}")
     }
 }
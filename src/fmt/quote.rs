@@ -0,0 +1,131 @@
+use super::{Formats, Modified, Modifier, Result, Style, Write};
+
+/// The delimiter used by [`Quote`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Delim {
+    /// `"`
+    #[default]
+    Double,
+
+    /// `'`
+    Single,
+
+    /// `` ` ``
+    Backtick,
+}
+super::derive!(
+    enum Delim {
+        Double,
+        Single,
+        Backtick,
+    }
+);
+
+impl Delim {
+    /// The delimiter as a character.
+    pub const fn char(self) -> char {
+        match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+            Self::Backtick => '`',
+        }
+    }
+}
+
+/// Surrounds the data with quotes, escaping the delimiter, `\`, and line
+/// breaks and tabs within it; e.g. `say "hi"` becomes `"say \"hi\""`.
+///
+/// Newlines, carriage returns, and tabs are written as `\n`, `\r`, and `\t`.
+/// Other characters are written as-is; use [`Debug`](struct@super::Debug) on
+/// strings for full escaping.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Quote<S> {
+    /// The delimiter to surround the data with.
+    pub delim: Delim,
+
+    /// The style being wrapped by this modifier.
+    pub style: S,
+}
+super::derive!(struct Quote<S!> { delim, style });
+
+impl<S: Style> Quote<S> {
+    /// Quote with `"`.
+    ///
+    /// Shorthand for `Quote { delim: Delim::Double, style }`.
+    pub const fn double(style: S) -> Self {
+        Self {
+            delim: Delim::Double,
+            style,
+        }
+    }
+
+    /// Quote with `'`.
+    ///
+    /// Shorthand for `Quote { delim: Delim::Single, style }`.
+    pub const fn single(style: S) -> Self {
+        Self {
+            delim: Delim::Single,
+            style,
+        }
+    }
+
+    /// Quote with `` ` ``.
+    ///
+    /// Shorthand for `Quote { delim: Delim::Backtick, style }`.
+    pub const fn backtick(style: S) -> Self {
+        Self {
+            delim: Delim::Backtick,
+            style,
+        }
+    }
+}
+
+impl<S: Style> Style for Quote<S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+
+impl<S: Style> Modifier for Quote<S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        let delim = self.delim.char();
+
+        f.write_char(delim)?;
+        self.style.format(&mut Escaper { f, delim }, data)?;
+        f.write_char(delim)
+    }
+}
+
+/// Escapes the delimiter, `\`, and line breaks and tabs with a `\`.
+struct Escaper<'w> {
+    f: &'w mut dyn Write,
+    delim: char,
+}
+
+impl Write for Escaper<'_> {
+    fn write_str(&mut self, mut data: &str) -> Result {
+        while let Some(i) = data.find([self.delim, '\\', '\n', '\r', '\t']) {
+            self.f.write_str(&data[..i])?;
+            self.f.write_char('\\')?;
+
+            // all candidates are ASCII
+            self.f.write_char(match data.as_bytes()[i] {
+                b'\n' => 'n',
+                b'\r' => 'r',
+                b'\t' => 't',
+                ch => ch as char,
+            })?;
+            data = &data[i + 1..];
+        }
+
+        self.f.write_str(data)
+    }
+}
@@ -0,0 +1,39 @@
+/// A repr for formatting data in an arbitrary base, from 2 to 36 (inclusive).
+/// Implemented for all integers.
+///
+/// Digits past 9 use the letters `a` to `z`, or `A` to `Z` if `upper` is set.
+/// Negative numbers are written as a `-` followed by their magnitude.
+///
+/// [`new`](Radix::new) rejects a `base` out of range, at compile time when
+/// called in a const context. A `Radix` with a bad `base` can still be built
+/// directly, in which case formatting with it fails with an
+/// [`Error`](super::Error) rather than panicking.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Radix {
+    /// The base to format in.
+    pub base: u32,
+
+    /// Whether or not letter digits should be uppercase.
+    pub upper: bool,
+}
+impl super::Style for Radix {
+    type Role = super::Base;
+
+    fn radix(&self) -> u32 {
+        self.base
+    }
+}
+super::derive!(struct Radix { base, upper });
+
+impl Radix {
+    /// Create a new `Radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` isn't in `2..=36`; in a const context, e.g.
+    /// `const B: Radix = Radix::new(40, false);`, this is a compile error.
+    pub const fn new(base: u32, upper: bool) -> Self {
+        assert!(2 <= base && base <= 36, "`Radix` base must be in `2..=36`");
+        Self { base, upper }
+    }
+}
@@ -0,0 +1,39 @@
+/// A repr for formatting numbers in scientific notation. Implemented for all
+/// integers and floats.
+///
+/// Without a precision, uses as few digits as possible while still
+/// representing the number exactly (or, for floats, while still round-tripping
+/// to the same value).
+///
+/// Equivalent of [`core::fmt::LowerExp`] and [`core::fmt::UpperExp`].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sci {
+    /// The number of digits after the decimal point, if fixed.
+    pub precision: Option<usize>,
+
+    /// Whether or not the `e` should be uppercase.
+    pub upper: bool,
+}
+impl super::Style for Sci {
+    type Role = super::Base;
+}
+super::derive!(struct Sci { precision, upper });
+
+impl Sci {
+    /// Create a new `Sci` using as few digits as possible.
+    pub const fn new(upper: bool) -> Self {
+        Self {
+            precision: None,
+            upper,
+        }
+    }
+
+    /// Create a new `Sci` with a fixed number of digits after the decimal
+    /// point.
+    pub const fn precision(precision: usize, upper: bool) -> Self {
+        Self {
+            precision: Some(precision),
+            upper,
+        }
+    }
+}
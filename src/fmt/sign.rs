@@ -0,0 +1,18 @@
+use super::{Base, Style};
+
+/// Forces a `+` before non-negative numbers. Implemented for all integers and
+/// floats, given that they implement `S`.
+///
+/// `NaN` never receives a sign.
+///
+/// Equivalent of the `+` flag in [`core::fmt`] (e.g. `{:+}`).
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sign<S>(pub S);
+impl<S: Style> Style for Sign<S> {
+    type Role = Base;
+
+    fn radix(&self) -> u32 {
+        self.0.radix()
+    }
+}
+super::derive!(struct Sign<S!>(s));
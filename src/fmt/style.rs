@@ -1,32 +1,156 @@
-use super::{Write, Format, Result};
+use super::{Format, Result, Write};
 
-pub trait Foo {
-    type IsModifier: Bool;
-    type Style = u32;
-}
+/// A specific way to format things, e.g. [`Display`](super::Display) or
+/// [`Hex`](super::Hex), for use by a [`Format`].
+///
+/// Some styles (like `Display`) are merely markers; some, like
+/// [`Debug`](struct@super::Debug), have methods on them to aid in using them;
+/// and some, like `Hex`, carry additional information inside about how things
+/// should be formatted.
+///
+/// `Role` must be [`Modified`] for [`Modifier`]s, [`Combined`] for
+/// [`Combinator`]s, and [`Base`] otherwise.
+///
+/// # Breaking change
+///
+/// `Role` was added after styles were first introduced, and has no default:
+/// existing implementations must add it, e.g. `type Role = Base;` for plain
+/// styles. Modifiers must also implement [`Modifier`] instead of having
+/// [`Format`] implemented for them.
+pub trait Style {
+    /// How this style formats data; see [`Role`].
+    type Role: Role;
 
-pub trait Bool: Sealed {}
+    /// The base numbers are written in by this style, so modifiers like
+    /// [`Group`](super::Group) can tell digits apart from other text (e.g.
+    /// the exponent in `1e21`).
+    fn radix(&self) -> u32 {
+        10
+    }
+}
 
-pub enum True {}
-pub enum False {}
+/// A wrapper around a style, providing a modification to that style; for
+/// example, [`Prefix`](super::Prefix) merely prints something before
+/// something else.
+///
+/// Modifiers apply to anything the inner style [formats](Formats), so types
+/// only ever need to implement [`Format`] for the base styles.
+pub trait Modifier: Style<Role = Modified> {
+    /// The style wrapped by this modifier.
+    type Inner: Style;
 
-impl Sealed for True {}
-impl Sealed for False {}
-impl Bool for True {}
-impl Bool for False {}
+    /// Apply this modifier to any type.
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        Self::Inner: Formats<T>;
+}
 
-pub trait Style: Foo<IsModifier = False> {}
-impl<S: Foo<IsModifier = False>> Style for S {}
+/// Formats data with two styles at once; for example,
+/// [`Then`](super::Then) formats with one style and then the other.
+///
+/// Like modifiers, combinators apply to anything both styles
+/// [format](Formats).
+pub trait Combinator: Style<Role = Combined> {
+    /// The first style being combined.
+    type First: Style;
 
-pub trait Modifier: Foo<IsModifier = True> {
-    type Inner: Foo;
+    /// The second style being combined.
+    type Second: Style;
 
+    /// Apply this combinator to any type.
     fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
     where
-        T: Format<Self::Inner> + ?Sized;
+        T: ?Sized,
+        Self::First: Formats<T>,
+        Self::Second: Formats<T>;
 }
 
-use private::Sealed;
+/// A style which can format `T`: either `T` implements <code>[Format]\<Self></code>,
+/// this is a [`Modifier`] whose inner style can format `T`, or this is a
+/// [`Combinator`] whose styles can both format `T`.
+///
+/// Automatically implemented; use this to accept any style (modifier or not)
+/// that can format some data.
+pub trait Formats<T: ?Sized>: Style {
+    /// Formats `data` in this style.
+    fn format(&self, f: &mut dyn Write, data: &T) -> Result;
+}
+
+impl<T, S> Formats<T> for S
+where
+    T: ?Sized,
+    S: Style,
+    S::Role: Dispatch<T, S>,
+{
+    fn format(&self, f: &mut dyn Write, data: &T) -> Result {
+        S::Role::format(self, f, data)
+    }
+}
+
+/// The role a [`Style`] plays; one of [`Base`], [`Modified`], or
+/// [`Combined`].
+pub trait Role: private::Sealed {}
+
+/// The role of styles which types implement [`Format`] for directly.
+pub enum Base {}
+
+/// The role of [`Modifier`]s.
+pub enum Modified {}
+
+/// The role of [`Combinator`]s.
+pub enum Combined {}
+
+impl Role for Base {}
+impl Role for Modified {}
+impl Role for Combined {}
+
+use private::Dispatch;
 mod private {
+    use super::*;
+
     pub trait Sealed {}
+    impl Sealed for Base {}
+    impl Sealed for Modified {}
+    impl Sealed for Combined {}
+
+    /// Picks how to format `T` based on the role of `S`. Since the roles are
+    /// distinct types, the impls can't overlap, where blanket impls on `S`
+    /// would.
+    pub trait Dispatch<T: ?Sized, S>: Role {
+        fn format(style: &S, f: &mut dyn Write, data: &T) -> Result;
+    }
+
+    impl<T, S> Dispatch<T, S> for Base
+    where
+        T: Format<S> + ?Sized,
+        S: Style,
+    {
+        fn format(style: &S, f: &mut dyn Write, data: &T) -> Result {
+            data.fmt(f, style)
+        }
+    }
+
+    impl<T, M> Dispatch<T, M> for Modified
+    where
+        T: ?Sized,
+        M: Modifier,
+        M::Inner: Formats<T>,
+    {
+        fn format(style: &M, f: &mut dyn Write, data: &T) -> Result {
+            style.apply(f, data)
+        }
+    }
+
+    impl<T, C> Dispatch<T, C> for Combined
+    where
+        T: ?Sized,
+        C: Combinator,
+        C::First: Formats<T>,
+        C::Second: Formats<T>,
+    {
+        fn format(style: &C, f: &mut dyn Write, data: &T) -> Result {
+            style.apply(f, data)
+        }
+    }
 }
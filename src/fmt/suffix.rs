@@ -0,0 +1,26 @@
+use super::{Display, Format, Formats, Modified, Modifier, Result, Style, Write};
+
+/// Prints `P` after the data.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Suffix<P, S>(pub P, pub S);
+super::derive!(struct Suffix<P!, S!>(p, s));
+
+impl<P: Format<Display>, S: Style> Style for Suffix<P, S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.1.radix()
+    }
+}
+impl<P: Format<Display>, S: Style> Modifier for Suffix<P, S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        self.1.format(f, data)?;
+        self.0.fmt(f, &Display)
+    }
+}
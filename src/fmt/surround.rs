@@ -0,0 +1,28 @@
+use super::{Display, Format, Formats, Modified, Modifier, Result, Style, Write};
+
+/// Prints `L` before the data and `R` after it; e.g. `Surround('[', ']',
+/// Display)`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Surround<L, R, S>(pub L, pub R, pub S);
+super::derive!(struct Surround<L!, R!, S!>(l, r, s));
+
+impl<L: Format<Display>, R: Format<Display>, S: Style> Style for Surround<L, R, S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.2.radix()
+    }
+}
+impl<L: Format<Display>, R: Format<Display>, S: Style> Modifier for Surround<L, R, S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        self.0.fmt(f, &Display)?;
+        self.2.format(f, data)?;
+        self.1.fmt(f, &Display)
+    }
+}
@@ -0,0 +1,351 @@
+//! Formatting from templates only known at runtime.
+//!
+//! A template is text with placeholders, e.g.
+//! `"{time} [{level:pad<5}] {addr:hex:pad0>8}"`. Each placeholder is a name,
+//! optionally followed by a list of `:`-separated options:
+//! - the name of a style in a [`Registry`], e.g. `hex` or `debug` (the default
+//!   is `display`);
+//! - `pad`, followed by an optional fill character, an alignment (`<`, `^`,
+//!   or `>`, as in std), and a width, e.g. `pad>10` or `pad.<20`. Any other
+//!   option starting with `pad`, e.g. `padded`, is the name of a style.
+//!
+//! Use `{{` and `}}` for literal braces.
+//!
+//! Placeholders are bound by name to [`FormatAny`]s when rendering. Values
+//! which don't support a placeholder's style are an error, rather than falling
+//! back to their usual style.
+
+use super::{
+    AnyStyle, Dir, Display, Format, FormatAny, Formats, Kind, Measure, Pad, Result, Write,
+};
+
+/// A mapping from names to styles, for use in templates.
+///
+/// Always falls back to the names known by [`AnyStyle::from_name`].
+#[derive(Clone, Copy, Default)]
+pub struct Registry<'a> {
+    entries: &'a [(&'a str, AnyStyle)],
+}
+
+impl<'a> Registry<'a> {
+    /// Create a new registry with extra named styles, which take priority
+    /// over the defaults.
+    pub const fn new(entries: &'a [(&'a str, AnyStyle)]) -> Self {
+        Self { entries }
+    }
+
+    /// Look up a style by name.
+    pub fn get(&self, name: &str) -> Option<AnyStyle> {
+        self.entries
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, style)| *style)
+            .or_else(|| AnyStyle::from_name(name))
+    }
+}
+
+/// An error encountered while parsing a template.
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset into the template at which the error occurred.
+    pub pos: usize,
+
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+/// The kinds of [`ParseError`].
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A `{` was never closed.
+    Unclosed,
+
+    /// A `}` was never opened; literal braces must be doubled (`}}`).
+    Unopened,
+
+    /// A placeholder's name was empty or contained invalid characters.
+    InvalidName,
+
+    /// An option wasn't a known style, nor a modifier.
+    UnknownStyle,
+
+    /// More than one style was given to a placeholder.
+    DuplicateStyle,
+
+    /// A `pad` option was malformed.
+    InvalidPad,
+}
+
+impl Format<Display> for ParseError {
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Unclosed => "unclosed `{`",
+            ParseErrorKind::Unopened => "unmatched `}`",
+            ParseErrorKind::InvalidName => "invalid placeholder name",
+            ParseErrorKind::UnknownStyle => "unknown style",
+            ParseErrorKind::DuplicateStyle => "more than one style",
+            ParseErrorKind::InvalidPad => "invalid padding",
+        })?;
+        f.write_str(" at byte ")?;
+        self.pos.fmt(f, &Display)
+    }
+}
+
+/// An error encountered while rendering a template.
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq)]
+pub struct RenderError {
+    /// The byte offset into the template of the placeholder that failed.
+    pub pos: usize,
+
+    /// What went wrong.
+    pub kind: RenderErrorKind,
+}
+
+/// The kinds of [`RenderError`].
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq)]
+pub enum RenderErrorKind {
+    /// No value was bound to the placeholder's name.
+    Missing,
+
+    /// The bound value doesn't support the placeholder's style.
+    Unsupported,
+
+    /// The writer returned an error.
+    Write,
+}
+
+impl Format<Display> for RenderError {
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        f.write_str(match self.kind {
+            RenderErrorKind::Missing => "no value for placeholder",
+            RenderErrorKind::Unsupported => "style unsupported by value",
+            RenderErrorKind::Write => "failed to write placeholder",
+        })?;
+        f.write_str(" at byte ")?;
+        self.pos.fmt(f, &Display)
+    }
+}
+
+/// A part of a template.
+enum Piece<'t> {
+    Lit(&'t str),
+    Hole {
+        pos: usize,
+        name: &'t str,
+        style: AnyStyle,
+        pad: Option<(char, Dir, usize)>,
+    },
+}
+
+/// A template, validated against a [`Registry`].
+///
+/// Parsing doesn't allocate; the template is re-read each time it's rendered.
+#[derive(Clone, Copy)]
+pub struct Template<'t, 'r> {
+    src: &'t str,
+    registry: Registry<'r>,
+}
+
+impl<'t> Template<'t, 'static> {
+    /// Parse a template using the default [`Registry`].
+    pub fn parse(src: &'t str) -> core::result::Result<Self, ParseError> {
+        Template::parse_with(src, Registry::default())
+    }
+}
+
+impl<'t, 'r> Template<'t, 'r> {
+    /// Parse a template, looking up styles in `registry`.
+    pub fn parse_with(
+        src: &'t str,
+        registry: Registry<'r>,
+    ) -> core::result::Result<Self, ParseError> {
+        let template = Self { src, registry };
+        for piece in template.pieces() {
+            piece?;
+        }
+
+        Ok(template)
+    }
+
+    /// Render the template, binding each placeholder to the value of the same
+    /// name in `args`.
+    pub fn render(
+        &self,
+        f: &mut dyn Write,
+        args: &[(&str, &dyn FormatAny)],
+    ) -> core::result::Result<(), RenderError> {
+        let mut pieces = self.pieces();
+        loop {
+            let start = pieces.pos;
+            let Some(piece) = pieces.next() else { break };
+            // already validated by `parse_with`
+            let Ok(piece) = piece else { unreachable!() };
+
+            let (pos, name, style, pad) = match piece {
+                Piece::Lit(lit) => {
+                    f.write_str(lit).map_err(|_| RenderError {
+                        pos: start,
+                        kind: RenderErrorKind::Write,
+                    })?;
+                    continue;
+                }
+                Piece::Hole {
+                    pos,
+                    name,
+                    style,
+                    pad,
+                } => (pos, name, style, pad),
+            };
+
+            let err = |kind| RenderError { pos, kind };
+            let value = args
+                .iter()
+                .find(|(n, _)| *n == name)
+                .ok_or(err(RenderErrorKind::Missing))?
+                .1;
+
+            if !value.supports(&style) {
+                return Err(err(RenderErrorKind::Unsupported));
+            }
+
+            let data = Bound { value, style };
+            match pad {
+                Some((with, align, count)) => Pad {
+                    align,
+                    with,
+                    count,
+                    kind: Kind::Full,
+                    measure: Measure::Chars,
+                    style: Display,
+                }
+                .format(f, &data),
+                None => data.fmt(f, &Display),
+            }
+            .map_err(|_| err(RenderErrorKind::Write))?;
+        }
+
+        Ok(())
+    }
+
+    fn pieces(&self) -> Pieces<'t, 'r> {
+        Pieces {
+            src: self.src,
+            pos: 0,
+            registry: self.registry,
+        }
+    }
+}
+
+/// A value bound to a style, for applying modifiers to.
+struct Bound<'a> {
+    value: &'a dyn FormatAny,
+    style: AnyStyle,
+}
+
+impl Format<Display> for Bound<'_> {
+    fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+        self.value.fmt(f, &self.style)
+    }
+}
+
+/// Iterates over the pieces of a template, parsing as it goes.
+struct Pieces<'t, 'r> {
+    src: &'t str,
+    pos: usize,
+    registry: Registry<'r>,
+}
+
+impl<'t> Iterator for Pieces<'t, '_> {
+    type Item = core::result::Result<Piece<'t>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.src[self.pos..];
+        let start = self.pos;
+        let err = |pos, kind| Some(Err(ParseError { pos, kind }));
+
+        let Some(i) = rest.find(['{', '}']) else {
+            self.pos = self.src.len();
+            return (!rest.is_empty()).then_some(Ok(Piece::Lit(rest)));
+        };
+
+        if i > 0 {
+            self.pos += i;
+            return Some(Ok(Piece::Lit(&rest[..i])));
+        }
+
+        // escaped braces
+        if rest[1..].starts_with(&rest[..1]) {
+            self.pos += 2;
+            return Some(Ok(Piece::Lit(&rest[..1])));
+        } else if rest.starts_with('}') {
+            return err(start, ParseErrorKind::Unopened);
+        }
+
+        let Some(end) = rest.find('}') else {
+            return err(start, ParseErrorKind::Unclosed);
+        };
+        self.pos += end + 1;
+
+        let mut options = rest[1..end].split(':');
+        let name = options.next().unwrap_or_default();
+        if name.is_empty() || !name.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+            return err(start + 1, ParseErrorKind::InvalidName);
+        }
+
+        let mut style = None;
+        let mut pad = None;
+        let mut opt_pos = start + 1 + name.len();
+        for opt in options {
+            opt_pos += 1;
+
+            if let Some(spec) = opt.strip_prefix("pad").filter(|spec| is_pad(spec)) {
+                match parse_pad(spec) {
+                    Some(p) if pad.is_none() => pad = Some(p),
+                    _ => return err(opt_pos, ParseErrorKind::InvalidPad),
+                }
+            } else {
+                match self.registry.get(opt) {
+                    Some(s) if style.is_none() => style = Some(s),
+                    Some(_) => return err(opt_pos, ParseErrorKind::DuplicateStyle),
+                    None => return err(opt_pos, ParseErrorKind::UnknownStyle),
+                }
+            }
+
+            opt_pos += opt.len();
+        }
+
+        Some(Ok(Piece::Hole {
+            pos: start,
+            name,
+            style: style.unwrap_or(AnyStyle::Display(Display)),
+            pad,
+        }))
+    }
+}
+
+/// Whether the rest of an option starting with `pad` is meant as its options,
+/// rather than the option being a style that happens to start with `pad`.
+fn is_pad(spec: &str) -> bool {
+    let mut chars = spec.chars();
+    let mut next = || chars.next().map(|ch| matches!(ch, '<' | '^' | '>'));
+    spec.is_empty() || next() == Some(true) || next() == Some(true)
+}
+
+/// Parses the options to `pad`: `[fill]<align><width>`.
+fn parse_pad(spec: &str) -> Option<(char, Dir, usize)> {
+    let align = |ch| match ch {
+        '<' => Some(Dir::Left),
+        '^' => Some(Dir::Center),
+        '>' => Some(Dir::Right),
+        _ => None,
+    };
+
+    let mut chars = spec.chars();
+    let first = chars.next()?;
+    let (with, dir) = match align(first) {
+        Some(dir) => (' ', dir),
+        None => (first, align(chars.next()?)?),
+    };
+
+    Some((with, dir, chars.as_str().parse().ok()?))
+}
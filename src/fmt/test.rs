@@ -1,4 +1,10 @@
-use super::{Binary, Debug, Dir, Display, Format, Hex, Kind, Pad, Prefix, Pretty, Result, Write};
+use core::time::Duration;
+
+use super::{
+    Binary, Bits, BoxedFormat, Bytes, Debug, Dir, Display, DynFormat, Fixed, Format, Group, Hex,
+    HumanDuration, Kind, Measure, Octal, Pad, Prefix, Pretty, Radix, Result, Sci, Sign, Signed,
+    Styled, Write,
+};
 use crate::format;
 
 #[test]
@@ -32,6 +38,35 @@ fn format() {
     assert_eq!(f, "x \"foobar\" 000123456 579 bar");
 }
 
+#[test]
+fn format_bindings() {
+    use core::cell::Cell;
+
+    let calls = Cell::new(0);
+    let expensive = || {
+        calls.set(calls.get() + 1);
+        255_u32
+    };
+
+    let f = format!(
+        n = expensive();
+        s = "foo".repeat(2);
+        n, " = ", n as Hex::prefix(false), " = ", n as Pad::right('0', 5, Display),
+        ' ', s as Debug, ' ', { s.len() },
+    );
+    assert_eq!(f, r#"255 = 0xff = 00255 "foofoo" 6"#);
+    assert_eq!(calls.get(), 1);
+
+    // fields of bindings are accessible too
+    let f = format!(r = 3..(2 + 5); r.start, "..", r.end);
+    assert_eq!(f, "3..7");
+
+    let mut f = String::new();
+    crate::writeln!(&mut f, n = expensive(); n as Binary).unwrap();
+    assert_eq!(f, "11111111\n");
+    assert_eq!(calls.get(), 2);
+}
+
 #[test]
 fn integer() {
     let x = 123_u8;
@@ -75,6 +110,7 @@ fn pad() {
         with: ' ',
         count: 12,
         kind: Kind::Full,
+        measure: Measure::Chars,
         style: Display,
     };
 
@@ -83,6 +119,7 @@ fn pad() {
         with: ' ',
         count: 6,
         kind: Kind::Full,
+        measure: Measure::Chars,
         style: Display,
     };
 
@@ -91,6 +128,7 @@ fn pad() {
         with: ' ',
         count: 5,
         kind: Kind::Mod,
+        measure: Measure::Chars,
         style: Display,
     };
 
@@ -107,6 +145,33 @@ fn pad() {
     args.write(&mut f).unwrap();
 
     assert_eq!(f, "      foobar\n abc  \nlonger-string  ");
+
+    let spad = Pad {
+        align: Dir::Sign,
+        with: '0',
+        count: 5,
+        kind: Kind::Full,
+        measure: Measure::Chars,
+        style: Display,
+    };
+    let sign = Pad {
+        style: Sign(Display),
+        align: spad.align,
+        with: spad.with,
+        count: spad.count,
+        kind: spad.kind,
+        measure: spad.measure,
+    };
+    let f = format!(
+        { -12 } as spad,
+        ' ',
+        { 12 } as spad,
+        ' ',
+        { 12 } as sign,
+        ' ',
+        "" as spad
+    );
+    assert_eq!(f, "-0012 00012 +0012 00000");
 }
 
 #[test]
@@ -171,7 +236,7 @@ fn pretty_helpers() {
         bar: ['b', 'a', 'r'],
     };
 
-    let f = bar.stringify(&Pretty(0));
+    let f = bar.stringify(&Pretty::new());
     let ex = r#"Bar {
     foo: Foo(
         123,
@@ -277,7 +342,7 @@ fn derive() {
         x: [1.23, 4.56, 7.89],
         y: 'y',
     }
-    .stringify(&Pretty(0));
+    .stringify(&Pretty::new());
     let ex = r#"Qux {
     x: [
         1.23,
@@ -288,3 +353,1240 @@ fn derive() {
 }"#;
     assert_eq!(f, ex);
 }
+
+/// A tiny xorshift PRNG, for generating test inputs.
+fn rng(seed: u64) -> impl FnMut() -> u64 {
+    let mut x = seed;
+    move || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    }
+}
+
+fn check_f64(x: f64) {
+    assert_eq!(
+        x.stringify(&Display),
+        std::format!("{x}"),
+        "{:#x}",
+        x.to_bits()
+    );
+    assert_eq!(
+        x.stringify(&Debug),
+        std::format!("{x:?}"),
+        "{:#x}",
+        x.to_bits()
+    );
+}
+
+fn check_f32(x: f32) {
+    assert_eq!(
+        x.stringify(&Display),
+        std::format!("{x}"),
+        "{:#x}",
+        x.to_bits()
+    );
+    assert_eq!(
+        x.stringify(&Debug),
+        std::format!("{x:?}"),
+        "{:#x}",
+        x.to_bits()
+    );
+}
+
+#[test]
+fn floats_special() {
+    for x in [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        0.3,
+        1e-4,
+        1e16,
+        1e15,
+        1e-5,
+        123456789.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::MIN,
+        f64::EPSILON,
+        f64::from_bits(1),
+        f64::from_bits(0x000f_ffff_ffff_ffff),
+    ] {
+        check_f64(x);
+    }
+
+    for x in [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        0.3,
+        1e-4,
+        1e16,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::MIN,
+        f32::EPSILON,
+        f32::from_bits(1),
+        f32::from_bits(0x007f_ffff),
+    ] {
+        check_f32(x);
+    }
+}
+
+#[test]
+fn floats_boundaries() {
+    // powers of two and ten sit on the edges of both the rounding intervals
+    // and the digit counts, so check them and their neighbours
+    for bits in (0..52).map(|i| 1 << i).chain((1..2047).map(|e| e << 52)) {
+        let x = f64::from_bits(bits);
+        for x in [
+            x,
+            f64::from_bits(x.to_bits() - 1),
+            f64::from_bits(x.to_bits() + 1),
+        ] {
+            check_f64(x);
+        }
+    }
+
+    for e in -323..=308 {
+        let x: f64 = std::format!("1e{e}").parse().unwrap();
+        for x in [
+            x,
+            f64::from_bits(x.to_bits() - 1),
+            f64::from_bits(x.to_bits() + 1),
+        ] {
+            check_f64(x);
+        }
+    }
+
+    for bits in (0..23).map(|i| 1 << i).chain((1..255).map(|e| e << 23)) {
+        let x = f32::from_bits(bits);
+        for x in [
+            x,
+            f32::from_bits(x.to_bits() - 1),
+            f32::from_bits(x.to_bits() + 1),
+        ] {
+            check_f32(x);
+        }
+    }
+
+    for e in -45..=38 {
+        let x: f32 = std::format!("1e{e}").parse().unwrap();
+        for x in [
+            x,
+            f32::from_bits(x.to_bits() - 1),
+            f32::from_bits(x.to_bits() + 1),
+        ] {
+            check_f32(x);
+        }
+    }
+}
+
+#[test]
+fn floats_random() {
+    let mut next = rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..20_000 {
+        check_f64(f64::from_bits(next()));
+        check_f32(f32::from_bits(next() as u32));
+    }
+
+    // short decimals are the most common inputs, and the most likely to
+    // expose off-by-one digit generation
+    for _ in 0..20_000 {
+        let x = (next() % 1_000_000) as f64 / 10_f64.powi((next() % 12) as i32);
+        check_f64(x);
+        check_f32(x as f32);
+    }
+}
+
+#[test]
+fn float_precision() {
+    let mut next = rng(0x9e37_79b9_7f4a_7c15);
+
+    let check = |x: f64| {
+        for precision in [0, 1, 2, 3, 6, 17, 30] {
+            let fixed = Fixed::new(precision);
+            let sci = Sci::precision(precision, false);
+            assert_eq!(x.stringify(&fixed), std::format!("{x:.precision$}"));
+            assert_eq!(x.stringify(&sci), std::format!("{x:.precision$e}"));
+            assert_eq!(
+                (x as f32).stringify(&fixed),
+                std::format!("{:.precision$}", x as f32)
+            );
+            assert_eq!(
+                (x as f32).stringify(&sci),
+                std::format!("{:.precision$e}", x as f32)
+            );
+        }
+
+        assert_eq!(x.stringify(&Sci::new(false)), std::format!("{x:e}"));
+        assert_eq!(x.stringify(&Sci::new(true)), std::format!("{x:E}"));
+        assert_eq!(x.stringify(&Sign(Display)), std::format!("{x:+}"));
+        assert_eq!(x.stringify(&Sign(Fixed::new(2))), std::format!("{x:+.2}"));
+    };
+
+    for x in [
+        0.0, -0.0, 0.5, 1.5, 2.5, 0.25, 0.125, 0.005, 9.995, 99.5, 1e300, 5e-324,
+    ] {
+        check(x);
+        check(-x);
+    }
+
+    for x in [
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+    ] {
+        check(x);
+    }
+
+    for _ in 0..500 {
+        check(f64::from_bits(next()));
+        check((next() % 100_000) as f64 / 1000.0);
+    }
+}
+
+#[test]
+fn int_precision() {
+    for x in [
+        0_i64,
+        5,
+        -5,
+        1200,
+        1250,
+        1350,
+        1251,
+        999,
+        99_950,
+        i64::MIN,
+        i64::MAX,
+    ] {
+        for precision in [0, 1, 2, 5, 20] {
+            let fixed = match precision {
+                0 => std::format!("{x}"),
+                _ => std::format!("{x}.{:0>precision$}", ""),
+            };
+            assert_eq!(x.stringify(&Fixed::new(precision)), fixed);
+            assert_eq!(
+                x.stringify(&Sci::precision(precision, true)),
+                std::format!("{x:.precision$E}"),
+            );
+        }
+
+        assert_eq!(x.stringify(&Sci::new(false)), std::format!("{x:e}"));
+        assert_eq!(x.stringify(&Sign(Display)), std::format!("{x:+}"));
+        assert_eq!(x.stringify(&Sign(Sci::new(false))), std::format!("{x:+e}"));
+    }
+
+    assert_eq!(7_u8.stringify(&Fixed::new(0)), "7");
+    assert_eq!(
+        u64::MAX.stringify(&Sci::new(false)),
+        std::format!("{:e}", u64::MAX)
+    );
+}
+
+#[test]
+fn int128() {
+    for x in [
+        0,
+        1,
+        -1,
+        0x1234_5678_9abc_def0,
+        i128::MIN,
+        i128::MIN + 1,
+        i128::MAX,
+    ] {
+        assert_eq!(x.stringify(&Display), std::format!("{x}"));
+        assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+        assert_eq!(x.stringify(&Pretty::new()), std::format!("{x:#?}"));
+        assert_eq!(
+            x.stringify(&Binary.twos_complement()),
+            std::format!("{x:b}")
+        );
+        assert_eq!(x.stringify(&Sci::new(false)), std::format!("{x:e}"));
+    }
+
+    let x = u128::MAX;
+    assert_eq!(x.stringify(&Display), std::format!("{x}"));
+    assert_eq!(x.stringify(&Hex(true)), std::format!("{x:X}"));
+    assert_eq!(x.stringify(&Binary), std::format!("{x:b}"));
+
+    assert_eq!(
+        i128::MIN.stringify(&Hex(false)),
+        "-80000000000000000000000000000000"
+    );
+    assert_eq!(i8::MIN.stringify(&Display), "-128");
+
+    let f = format!(
+        x as Pad::right('_', 42, Display),
+        ' ',
+        { -12_i128 } as Pad::left('.', 5, Hex(false)),
+    );
+    assert_eq!(f, std::format!("{x:_>42} -c..."));
+}
+
+#[test]
+fn radix() {
+    let x = 0o755_u16;
+    let f = format!(x as Octal, ' ', x as Octal::prefix(), ' ', { u128::MAX }
+        as Octal);
+    assert_eq!(f, std::format!("755 0o755 {:o}", u128::MAX));
+
+    assert_eq!(1_295_u32.stringify(&Radix::new(36, false)), "zz");
+    assert_eq!(1_295_u32.stringify(&Radix::new(36, true)), "ZZ");
+    assert_eq!((-35_i8).stringify(&Radix::new(36, false)), "-z");
+    assert_eq!(0_u8.stringify(&Radix::new(7, false)), "0");
+    const TERNARY: Radix = Radix::new(3, false);
+    assert_eq!(5_u8.stringify(&TERNARY), "12");
+
+    let mut f = String::new();
+    let bad = Radix {
+        base: 40,
+        upper: false,
+    };
+    assert_eq!((-5_i32).fmt(&mut f, &bad), Err(super::Error));
+    assert_eq!(
+        5_u8.fmt(&mut f, &Radix { base: 1, ..bad }),
+        Err(super::Error)
+    );
+    assert_eq!(f, "");
+    assert_eq!(
+        i128::MIN.stringify(&Radix::new(2, false)),
+        std::format!("-1{:0>127}", "")
+    );
+
+    for base in 2..=36 {
+        let x = 123_456_789_u64;
+        let parsed = u64::from_str_radix(&x.stringify(&Radix::new(base, false)), base);
+        assert_eq!(parsed, Ok(x));
+    }
+}
+
+#[test]
+fn bits() {
+    for x in [0_i16, 1, -1, 0x1f, -0x1f, i16::MIN, i16::MAX] {
+        let twos = format!(
+            x as Hex(false).twos_complement(),
+            ' ',
+            x as Octal.twos_complement(),
+            ' ',
+            x as Binary.twos_complement(),
+        );
+        assert_eq!(twos, std::format!("{x:x} {x:o} {x:b}"));
+
+        let full = format!(
+            x as Hex(true).twos_complement().full_width(),
+            ' ',
+            x as Binary.twos_complement().full_width(),
+        );
+        assert_eq!(full, std::format!("{x:04X} {x:016b}"));
+    }
+
+    // negative numbers are consistently written as a magnitude by default
+    let f = format!({ -5_i8 } as Hex(false), ' ', { -5_i8 } as Binary, ' ', { -5_i8 } as Octal);
+    assert_eq!(f, "-5 -101 -5");
+    for x in [-1_i16, -0x1f, i16::MIN] {
+        let f = format!(x as Hex(false), ' ', x as Binary, ' ', x as Octal);
+        let m = x.unsigned_abs();
+        assert_eq!(f, std::format!("-{m:x} -{m:b} -{m:o}"));
+    }
+    let x = i8::MIN;
+    let f = format!(x as Hex(false), ' ', x as Binary, ' ', x as Octal);
+    assert_eq!(f, "-80 -10000000 -200");
+
+    let f = format!(
+        { 0x1f_u16 } as Hex(false).full_width(),
+        ' ',
+        { -1_i8 } as Binary.full_width(),
+        ' ',
+        { i8::MIN } as Octal.full_width(),
+        ' ',
+        { u128::MAX } as Bits { style: Hex(false), signed: Signed::Magnitude, full: true },
+    );
+    assert_eq!(f, std::format!("001f -00000001 -200 {:x}", u128::MAX));
+    assert_eq!(
+        (-1_i8).stringify(&Binary.full_width().twos_complement()),
+        "11111111"
+    );
+}
+
+#[test]
+fn group() {
+    let f = format!(
+        { 1_048_576 } as Group::thousands(',', Display),
+        ' ',
+        { -1_048_576 } as Group::new('_', 3, Display),
+        ' ',
+        { 0 } as Group::thousands(',', Display),
+        ' ',
+        { 123 } as Group::thousands(',', Display),
+    );
+    assert_eq!(f, "1,048,576 -1_048_576 0 123");
+
+    let f = format!(
+        { 0xdead_beef_u32 } as Prefix("0x", Group::new('_', 4, Hex(false))),
+        ' ',
+        { 0b1_0101_0101_u16 } as Group::new('_', 4, Binary),
+        ' ',
+        { 1234567.891 } as Group::thousands(',', Display),
+        ' ',
+        { -1234567.5_f32 } as Group::thousands(',', Fixed::new(1)),
+        ' ',
+        { 12345 } as Group::new(',', 0, Display),
+    );
+    assert_eq!(
+        f,
+        "0xdead_beef 1_0101_0101 1,234,567.891 -1,234,567.5 12345"
+    );
+
+    // exponents aren't part of the integer part
+    let f = format!(
+        { 1e21_f64 } as Group::new(',', 3, Debug),
+        ' ',
+        { 1234.5e-300_f64 } as Group::thousands(',', Sci::new(false)),
+        ' ',
+        { -123_456_789_i64 } as Group::thousands(',', Sci::new(true)),
+        ' ',
+        { 0x1e21_u16 } as Group::new('_', 2, Hex(false)),
+        ' ',
+        { 1_295_u32 } as Group::new('_', 1, Radix::new(36, false)),
+    );
+    assert_eq!(f, "1e21 1.2345e-297 -1.23456789E8 1e_21 z_z");
+
+    // the base is taken from the style at runtime
+    use super::{AnyStyle, Suffix};
+    let f = format!(
+        { 1234 } as Group::thousands(',', Suffix("def", Radix::new(10, false))),
+        ' ',
+        { 0xbeef } as Group::new('_', 2, Radix::new(16, false)),
+        ' ',
+        { 0xbeef } as Group::new('_', 2, AnyStyle::Hex(Hex(false))),
+        ' ',
+        { 1e21_f64 } as Group::new(',', 3, AnyStyle::Debug(Debug)),
+    );
+    assert_eq!(f, "1,234def be_ef be_ef 1e21");
+
+    // padding sees the grouped width
+    let f = format!({ 1_048_576 } as Pad::right(' ', 10, Group::thousands(',', Display)));
+    assert_eq!(f, " 1,048,576");
+}
+
+#[test]
+fn bytes() {
+    let f = format!(
+        { 0 } as Bytes::binary(),
+        ' ',
+        { 1023 } as Bytes::binary(),
+        ' ',
+        { 1024 } as Bytes::binary(),
+        ' ',
+        { 1536 } as Bytes::binary(),
+        ' ',
+        { 1_048_575 } as Bytes::binary(),
+        ' ',
+        { -3_i64 * 1024 * 1024 * 1024 } as Bytes::binary(),
+    );
+    assert_eq!(f, "0 B 1023 B 1 KiB 1.5 KiB 1 MiB -3 GiB");
+
+    let f = format!(
+        { 999 } as Bytes::decimal(),
+        ' ',
+        { 1_250 } as Bytes::decimal(),
+        ' ',
+        { 1_050 } as Bytes::decimal(),
+        ' ',
+        { 1_150 } as Bytes::decimal(),
+        ' ',
+        { 999_960_u32 } as Bytes::decimal(),
+        ' ',
+        { u128::MAX } as Bytes::decimal(),
+    );
+    assert_eq!(f, "999 B 1.2 kB 1 kB 1.2 kB 1 MB 340282366920938.5 YB");
+}
+
+#[test]
+fn human_duration() {
+    let check = |d: Duration, s: &str| assert_eq!(format!(d as HumanDuration), s);
+
+    check(Duration::ZERO, "0ns");
+    check(Duration::from_nanos(40), "40ns");
+    check(Duration::from_nanos(12_345), "12.3µs");
+    check(Duration::from_micros(250_000), "250ms");
+    check(Duration::from_millis(1_500), "1.5s");
+    check(Duration::from_millis(59_999), "59.9s");
+    check(Duration::from_secs(60), "1m 00s");
+    check(Duration::from_millis(123_900), "2m 03s");
+    check(Duration::from_secs(3723), "1h 02m 03s");
+    check(Duration::from_secs(3 * 86400 + 5), "3d 00h 00m 05s");
+}
+
+#[test]
+fn containers() {
+    use core::cell::{Cell, RefCell};
+    use core::marker::PhantomData;
+    use std::borrow::Cow;
+    use std::boxed::Box;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    assert_eq!((1,).stringify(&Debug), "(1,)");
+    assert_eq!((1, "two", '3').stringify(&Debug), r#"(1, "two", '3')"#);
+    let x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    let x = (1, (2.5, ()));
+    assert_eq!(x.stringify(&Pretty::new()), std::format!("{x:#?}"));
+    assert_eq!((1,).stringify(&Pretty::new()), std::format!("{:#?}", (1,)));
+
+    let x = (
+        Box::new(1),
+        Rc::new("rc"),
+        Arc::new([1, 2]),
+        Cow::<str>::Borrowed("cow"),
+        Cow::<str>::Owned("owned".to_string()),
+    );
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    assert_eq!(format!({ Box::new(255) } as Hex(false)), "ff");
+
+    let x = Cell::new(5);
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    assert_eq!(x.stringify(&Pretty::new()), std::format!("{x:#?}"));
+
+    let x = RefCell::new(vec![1]);
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    let guard = x.borrow_mut();
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    assert_eq!(x.stringify(&Pretty::new()), std::format!("{x:#?}"));
+    drop(guard);
+
+    let x = PhantomData::<u8>;
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+}
+
+#[test]
+fn collections() {
+    use std::collections::{
+        BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque,
+    };
+
+    let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    assert_eq!(map.stringify(&Debug), std::format!("{map:?}"));
+    assert_eq!(map.stringify(&Pretty::new()), std::format!("{map:#?}"));
+
+    let set = BTreeSet::from([(1, 'x'), (2, 'y')]);
+    assert_eq!(set.stringify(&Debug), std::format!("{set:?}"));
+    assert_eq!(set.stringify(&Pretty::new()), std::format!("{set:#?}"));
+
+    let x = (
+        VecDeque::from([1, 2]),
+        LinkedList::from([3]),
+        BinaryHeap::from([4]),
+        HashMap::from([(5, "five")]),
+        HashSet::from([6]),
+    );
+    assert_eq!(x.stringify(&Debug), std::format!("{x:?}"));
+    assert_eq!(x.stringify(&Pretty::new()), std::format!("{x:#?}"));
+
+    assert_eq!(BTreeMap::<u8, u8>::new().stringify(&Debug), "{}");
+
+    let mut f = String::new();
+    Debug
+        .dbg_map(&mut f)
+        .entry("a", &[1])
+        .entry_with(|f| f.write_char('b'), |f| f.write_str("<none>"))
+        .non_exhaustive()
+        .unwrap();
+    assert_eq!(f, r#"{"a": [1], b: <none>, ...}"#);
+
+    let mut f = String::new();
+    Debug
+        .dbg_set(&mut f)
+        .entry(&1)
+        .entry_styled(&255, &Hex(false))
+        .non_exhaustive()
+        .unwrap();
+    assert_eq!(f, "{1, ff, ...}");
+
+    let mut f = String::new();
+    Pretty::new()
+        .dbg_list(&mut f)
+        .entries([1, 2])
+        .non_exhaustive()
+        .unwrap();
+    assert_eq!(f, "[\n    1,\n    2,\n    ...\n]");
+}
+
+#[test]
+fn pretty_width() {
+    use super::derive;
+
+    #[allow(dead_code)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    derive!(struct Point { x, y });
+
+    #[allow(dead_code)]
+    struct Line {
+        name: &'static str,
+        points: std::vec::Vec<Point>,
+    }
+    derive!(struct Line { name, points });
+
+    let pairs: std::vec::Vec<(u8, u8)> = (0..4).map(|i| (i, i * 2)).collect();
+    let f = pairs.stringify(&Pretty::new().width(80));
+    assert_eq!(f, "[(0, 0), (1, 2), (2, 4), (3, 6)]");
+
+    let f = pairs.stringify(&Pretty::new().width(20));
+    assert_eq!(
+        f,
+        "[\n    (0, 0),\n    (1, 2),\n    (2, 4),\n    (3, 6),\n]"
+    );
+
+    let line = Line {
+        name: "zigzag",
+        points: std::vec![Point { x: 0, y: 0 }, Point { x: 10, y: -10 }],
+    };
+    let f = line.stringify(&Pretty::new().width(40).indent("  "));
+    let ex = r#"Line {
+  name: "zigzag",
+  points: [
+    Point { x: 0, y: 0 },
+    Point { x: 10, y: -10 },
+  ],
+}"#;
+    assert_eq!(f, ex);
+
+    // `  points: [..],` is exactly 58 columns wide
+    let f = line.stringify(&Pretty::new().width(58).indent("  "));
+    assert!(f.contains("\n  points: [Point { x: 0, y: 0 }, Point { x: 10, y: -10 }],\n"));
+    let f = line.stringify(&Pretty::new().width(57).indent("  "));
+    assert_eq!(f, ex);
+
+    // map values start after their keys
+    let map = std::collections::BTreeMap::from([("a", std::vec![1, 2, 3]), ("b", std::vec![4])]);
+    let f = map.stringify(&Pretty::new().width(26));
+    assert_eq!(f, r#"{"a": [1, 2, 3], "b": [4]}"#);
+    let f = map.stringify(&Pretty::new().width(19));
+    assert_eq!(f, "{\n    \"a\": [1, 2, 3],\n    \"b\": [4],\n}");
+    let f = map.stringify(&Pretty::new().width(18));
+    assert_eq!(
+        f,
+        "{\n    \"a\": [\n        1,\n        2,\n        3,\n    ],\n    \"b\": [4],\n}"
+    );
+
+    // single-element tuples and empty groups
+    let f = ((1,), [(); 0], Point { x: 1, y: 2 }).stringify(&Pretty::new().width(80));
+    assert_eq!(f, "((1,), [], Point { x: 1, y: 2 })");
+}
+
+#[test]
+fn template() {
+    use super::template::{
+        ParseError, ParseErrorKind, Registry, RenderError, RenderErrorKind, Template,
+    };
+    use super::{AnyStyle, Error, FormatAny};
+    use std::string::String;
+
+    let render = |t: &Template, args: &[(&str, &dyn FormatAny)]| {
+        let mut s = String::new();
+        t.render(&mut s, args).map(|_| s)
+    };
+
+    let t = Template::parse("{time:duration} [{level:pad<5}] {addr:hex:pad0>8} {{{size:ibytes}}}")
+        .unwrap();
+    let args: [(&str, &dyn FormatAny); 4] = [
+        ("time", &Duration::from_millis(1_500)),
+        ("level", &"info"),
+        ("addr", &0xbeef_u32),
+        ("size", &1536_u64),
+    ];
+    let f = render(&t, &args);
+    assert_eq!(f.as_deref(), Ok("1.5s [info ] 0000beef {1.5 KiB}"));
+
+    let f = render(&t, &args[..3]);
+    let e = RenderError {
+        pos: 52,
+        kind: RenderErrorKind::Missing,
+    };
+    assert_eq!(f, Err(e));
+    let f = render(&Template::parse("a {time}").unwrap(), &args);
+    let e = RenderError {
+        pos: 2,
+        kind: RenderErrorKind::Unsupported,
+    };
+    assert_eq!(f, Err(e));
+
+    // `Var`s format with their own style
+    let x = 12;
+    let s = Pad::right('*', 4, Hex(true));
+    let t = Template::parse("<{x}>").unwrap();
+    let f = render(&t, &[("x", &super::args::Var::new(&x, &s))]);
+    assert_eq!(f.as_deref(), Ok("<***C>"));
+
+    let registry = [
+        ("upper", AnyStyle::Hex(Hex(true))),
+        ("hex", AnyStyle::Debug(Debug)),
+    ];
+    let t = Template::parse_with("{a:upper:pad.^6} {b:hex}", Registry::new(&registry)).unwrap();
+    let f = render(&t, &[("a", &255_u8), ("b", &"hi")]);
+    assert_eq!(f.as_deref(), Ok("..FF.. \"hi\""));
+
+    // styles may start with `pad`
+    let registry = [("padded", AnyStyle::Debug(Debug))];
+    let t = Template::parse_with("{a:padded:pad>4}", Registry::new(&registry)).unwrap();
+    assert_eq!(render(&t, &[("a", &'c')]).as_deref(), Ok(" 'c'"));
+
+    // write errors are reported at the start of the failing piece
+    struct Fail;
+    impl Write for Fail {
+        fn write_str(&mut self, _: &str) -> Result {
+            Err(Error)
+        }
+    }
+    let e = RenderError {
+        pos: 0,
+        kind: RenderErrorKind::Write,
+    };
+    let t = Template::parse("abc {x}").unwrap();
+    assert_eq!(t.render(&mut Fail, &[("x", &1)]), Err(e));
+
+    let check = |src, pos, kind| {
+        let e = Template::parse(src).err();
+        assert_eq!(e, Some(ParseError { pos, kind }), "{src:?}");
+    };
+    check("abc {x", 4, ParseErrorKind::Unclosed);
+    check("abc } x", 4, ParseErrorKind::Unopened);
+    check("{x}{}", 4, ParseErrorKind::InvalidName);
+    check("{x}{a b}", 4, ParseErrorKind::InvalidName);
+    check("{x:hex:nope}", 7, ParseErrorKind::UnknownStyle);
+    check("{x:hex:pad>3:bin}", 13, ParseErrorKind::DuplicateStyle);
+    check("{x:pad}", 3, ParseErrorKind::InvalidPad);
+    check("{x:pad>}", 3, ParseErrorKind::InvalidPad);
+    check("{x:pad>3:pad<3}", 9, ParseErrorKind::InvalidPad);
+
+    let e = Template::parse("{x:nope}").err().unwrap();
+    assert_eq!(e.stringify(&Display), "unknown style at byte 3");
+}
+
+#[test]
+fn dyn_format() {
+    use std::vec::Vec;
+
+    let pipeline: Vec<BoxedFormat> = std::vec![
+        Styled::new("addr=", Display).boxed(),
+        Styled::new(0xbeef_u32, Prefix("0x", Hex(false))).boxed(),
+        Styled::new(", name=", Display).boxed(),
+        Styled::new("foo", Debug).boxed(),
+        Box::new(Styled::new(12, Pad::right('0', 4, Display))),
+    ];
+    let mut f = String::new();
+    for d in &pipeline {
+        DynFormat::fmt(d, &mut f).unwrap();
+    }
+    assert_eq!(f, r#"addr=0xbeef, name="foo"0012"#);
+
+    let s = Styled::new(5_u8, Binary);
+    let d: &dyn DynFormat = &s;
+    assert_eq!(d.stringify(&Display), "101");
+    assert_eq!(
+        format!("[", d as Pad::center('-', 7, Display), "]"),
+        "[--101--]"
+    );
+
+    let args: &dyn DynFormat = &crate::format_args!("x=", 3);
+    assert_eq!(format!(args, ' ', d), "x=3 101");
+}
+
+#[test]
+fn core_fmt_bridge() {
+    let x = Styled::new(0xbeef_u32, Prefix("0x", Hex(true)));
+    assert_eq!(std::format!("[{x}] {x:?}"), "[0xBEEF] 0xBEEF");
+
+    // width, fill, alignment and precision apply to the output, like strings
+    assert_eq!(
+        std::format!("[{x:8}] [{x:*>8?}] [{x:^9.4}] [{x:+#}]"),
+        "[0xBEEF  ] [**0xBEEF] [  0xBE   ] [0xBEEF]"
+    );
+
+    let v = Styled::new([1, 2], Pretty::new());
+    assert_eq!(std::format!("{v}"), "[\n    1,\n    2,\n]");
+
+    let d: &dyn DynFormat = &Styled::new("hi", Debug);
+    assert_eq!(std::format!("{d}"), "\"hi\"");
+}
+
+#[test]
+fn any_style() {
+    use super::{AnyStyle, FormatAny};
+
+    let line = |flag| {
+        let s = AnyStyle::from_name(flag).unwrap();
+        let t = Duration::from_millis(1_500);
+        format!(
+            "x=",
+            { 255_u8 } as s,
+            " name=",
+            "foo" as s,
+            " t=",
+            t as s,
+            " [",
+            { 10 } as Pad::right(' ', 5, s),
+            "]",
+        )
+    };
+    assert_eq!(line("display"), "x=255 name=foo t=1.5s [   10]");
+    assert_eq!(line("debug"), r#"x=255 name="foo" t=1.5s [   10]"#);
+    assert_eq!(line("hex"), "x=ff name=foo t=1.5s [    a]");
+    assert_eq!(line("bin"), "x=11111111 name=foo t=1.5s [ 1010]");
+    assert!(AnyStyle::from_name("nope").is_none());
+
+    assert_eq!(1.5_f64.stringify(&AnyStyle::Sci(Sci::new(false))), "1.5e0");
+    assert_eq!(true.stringify(&AnyStyle::Hex(Hex(false))), "true");
+
+    // collections support the styles their items do, falling back to `Debug`
+    let s = AnyStyle::from_name("hex").unwrap();
+    let f = format!(
+        { vec![Some(1), None] } as s,
+        ' ',
+        { [1.5, 2.0].as_slice() } as s,
+        ' ',
+        { Some("x") } as AnyStyle::Pretty(Pretty::new()),
+    );
+    assert_eq!(f, "[Some(1), None] [1.5, 2.0] Some(\n    \"x\",\n)");
+    assert!(!vec![1].supports(&s));
+    assert!(None::<u8>.supports(&AnyStyle::Debug(Debug)));
+}
+
+#[test]
+fn modifiers() {
+    use super::Formats;
+
+    struct Local;
+    impl Format<Display> for Local {
+        fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+            f.write_str("local")
+        }
+    }
+    // still accepted, but does nothing
+    crate::stylable!(Local);
+
+    let s = Pad::center('*', 9, Prefix("<", Display));
+    assert_eq!(format!(Local as s, { &&Local } as s), "*<local***<local**");
+
+    let s = Pad::center('*', 11, Prefix("<", Group::thousands('_', Display)));
+    assert_eq!(format!({ 12345 } as s), "**<12_345**");
+
+    let mut f = String::new();
+    s.format(&mut f, &Box::new(-1234)).unwrap();
+    assert_eq!(f, "**<-1_234**");
+    assert_eq!(super::stringify(&12345, &s), "**<12_345**");
+
+    // the sign goes outside any modifier it wraps
+    let f = format!(
+        { 42 } as Sign(Pad::right('0', 5, Display)),
+        ' ',
+        { 1234567 } as Sign(Group::thousands(',', Display)),
+        ' ',
+        { -2.5 } as Sign(Prefix("~", Fixed::new(2))),
+    );
+    assert_eq!(f, "+00042 +1,234,567 ~-2.50");
+}
+
+#[test]
+fn style_ext() {
+    use super::{Both, Case, Formats, StyleExt, Then};
+
+    let s = Hex(false).prefixed("0x").pad_right('0', 9).upper();
+    let nested = Pad::right('0', 9, Prefix("0x", Hex(true)));
+    assert_eq!(format!({ 0xbeef } as s), "0000xBEEF");
+    assert_eq!(format!({ 0xbeef } as s), format!({ 0xbeef } as nested));
+    let s = s.lower();
+    assert_eq!(format!({ 0xbeef } as s), "0000xbeef");
+
+    let s = Display.signed().grouped(',', 3).pad_left('.', 9);
+    assert_eq!(format!({ 12345 } as s), "+12,345..");
+
+    let s = Radix::new(36, false).upper().then(Display);
+    assert_eq!(format!({ 35 } as s), "Z35");
+    let s = Hex(false).twos_complement().upper().both(" is ", Display);
+    assert_eq!(format!({ -1_i8 } as s), "FF is -1");
+    let s = Then(Sci::new(false), Sci::new(false).upper());
+    assert_eq!(format!({ 1500 } as s), "1.5e31.5E3");
+
+    let s = Display.both(" = ", Hex::prefix(false).upper().pad_left(' ', 6));
+    assert_eq!(format!({ 255 } as s, ";"), "255 = 0xFF  ;");
+
+    struct Local;
+    impl Format<Display> for Local {
+        fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+            f.write_str("local")
+        }
+    }
+    impl Format<Debug> for Local {
+        fn fmt(&self, f: &mut dyn Write, _: &Debug) -> Result {
+            f.write_str("Local")
+        }
+    }
+
+    let s = Both::new(Display.pad_center('-', 7), '/', Debug).then(Debug.prefixed('!'));
+    let mut f = String::new();
+    s.format(&mut f, &Local).unwrap();
+    assert_eq!(f, "-local-/Local!Local");
+    assert_eq!(format!(Local as s), "-local-/Local!Local");
+}
+
+#[test]
+fn decorations() {
+    use super::{Case, Delim, Quote, StyleExt, Suffix, Surround};
+
+    let s = Suffix("ms", Display);
+    assert_eq!(format!({ 150 } as s), "150ms");
+
+    let s = Surround('[', "]>", Hex(false));
+    assert_eq!(format!({ 255 } as s), "[ff]>");
+
+    let s = Quote::double(Display);
+    assert_eq!(format!("say \"hi\"" as s), r#""say \"hi\"""#);
+    assert_eq!(format!("it's" as s), r#""it's""#);
+    assert_eq!(format!(r"C:\dir" as s), r#""C:\\dir""#);
+    assert_eq!(format!("" as s), r#""""#);
+    assert_eq!(format!("a\n\tb\r\n" as s), r#""a\n\tb\r\n""#);
+
+    let s = Quote::single(Display);
+    assert_eq!(format!("it's \"x\"" as s), r#"'it\'s "x"'"#);
+
+    let s = Display.quoted(Delim::Backtick).pad_left('.', 8);
+    assert_eq!(format!("a`b" as s), r"`a\`b`..");
+
+    let s = Quote::backtick(Debug);
+    assert_eq!(format!('`' as s), r"`'\`'`");
+
+    let s = Hex(false)
+        .upper()
+        .suffixed('h')
+        .surrounded('(', ')')
+        .pad_center(' ', 8);
+    assert_eq!(format!({ 0xabc } as s), " (ABCh) ");
+
+    let s = Display
+        .grouped(',', 3)
+        .quoted(Delim::Double)
+        .suffixed(" bytes");
+    assert_eq!(format!({ 1234567 } as s), "\"1,234,567\" bytes");
+}
+
+#[test]
+fn truncate() {
+    use super::{Error, Formats, StyleExt, Truncate};
+    use std::string::String;
+
+    struct Chunks(&'static [&'static str]);
+    impl Format<Display> for Chunks {
+        fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+            for chunk in self.0 {
+                f.write_str(chunk)?;
+            }
+
+            // the truncator should stop us before this
+            panic!("wrote everything");
+        }
+    }
+
+    let s = Truncate::ellipsis(8, "…", Display);
+    assert_eq!(format!("hello world" as s), "hello w…");
+    assert_eq!(format!("hello wo" as s), "hello wo");
+    assert_eq!(format!("hello w" as s), "hello w");
+    assert_eq!(format!("" as s), "");
+    assert_eq!(format!("héllo wörld" as s), "héllo w…");
+
+    let s = Truncate::new(3, Display);
+    assert_eq!(format!({ 123456 } as s), "123");
+    assert_eq!(format!({ 123 } as s), "123");
+    assert_eq!(format!({ 1.5 } as s), "1.5");
+
+    let s = Display.ellipsized(5, "...");
+    assert_eq!(format!("abcdef" as s), "ab...");
+    assert_eq!(
+        format!({ Chunks(&["ab", "c", "d", "ef", "g"]) } as s),
+        "ab..."
+    );
+    assert_eq!(format!({ Chunks(&["abcde", "f"]) } as s), "ab...");
+
+    let s = Display.ellipsized(2, "...");
+    assert_eq!(format!("abc" as s), "..");
+    assert_eq!(format!("ab" as s), "ab");
+
+    let s = Display.ellipsized(0, "~");
+    assert_eq!(format!("a" as s), "");
+    assert_eq!(format!("" as s), "");
+
+    let s = Debug.truncated(6).pad_left('.', 8).surrounded('|', '|');
+    assert_eq!(format!("abcdefgh" as s), "|\"abcde..|");
+    assert_eq!(format!('x' as s), "|'x'.....|");
+
+    // ellipses wider than `max` are cut to fit
+    let s = Truncate::ellipsis(3, "[snip]..", Display);
+    assert_eq!(format!("abcdef" as s), "[sn");
+    let s = Truncate::ellipsis(10, "01234567", Display);
+    assert_eq!(format!("abcdefghijk" as s), "ab01234567");
+
+    // but ellipses over 8 characters are rejected, rather than changed
+    let s = Truncate {
+        ellipsis: "012345678",
+        ..Truncate::new(10, Display)
+    };
+    assert_eq!(s.format(&mut String::new(), "abcdefghijk"), Err(Error));
+    let long = std::panic::catch_unwind(|| Truncate::ellipsis(10, "[truncated]", Display));
+    assert!(long.is_err());
+
+    // measured in columns, keeping clusters whole
+    let s = Truncate::ellipsis(5, "…", Display).measured(Measure::Columns);
+    assert_eq!(format!("日本語です" as s), "日本…");
+    assert_eq!(format!("日本a" as s), "日本a");
+    assert_eq!(format!("ab\u{301}cdef" as s), "ab\u{301}cd…");
+    let s = Truncate::new(2, Display).measured(Measure::Graphemes);
+    assert_eq!(format!("e\u{301}e\u{301}e" as s), "e\u{301}e\u{301}");
+}
+
+#[test]
+fn width() {
+    use super::WidthCounter;
+
+    let cases = [
+        // text, chars, graphemes, columns
+        ("abc", 3, 3, 3),
+        ("e\u{301}", 2, 1, 1),
+        ("日本語", 3, 3, 6),
+        ("ｈｉ!", 3, 3, 5),
+        ("한국", 2, 2, 4),
+        ("\u{1112}\u{1161}\u{11ab}", 3, 1, 2),
+        ("\r\n", 2, 1, 0),
+        ("\n\r", 2, 2, 0),
+        ("👍", 1, 1, 2),
+        ("👍🏽", 2, 1, 2),
+        ("👨\u{200d}👩\u{200d}👧", 5, 1, 2),
+        ("🇳🇴", 2, 1, 2),
+        ("🇳🇴🇸", 3, 2, 4),
+        ("❤", 1, 1, 1),
+        ("❤\u{fe0f}", 2, 1, 2),
+        ("a\tb\n", 4, 4, 2),
+        ("\u{301}x", 2, 2, 1),
+        ("", 0, 0, 0),
+    ];
+
+    for (text, chars, graphemes, columns) in cases {
+        assert_eq!(Measure::Chars.of(text), chars, "{text:?}");
+        assert_eq!(Measure::Graphemes.of(text), graphemes, "{text:?}");
+        assert_eq!(Measure::Columns.of(text), columns, "{text:?}");
+    }
+
+    // clusters can span writes
+    let mut f = WidthCounter::new(Measure::Columns);
+    f.write_str("日e").unwrap();
+    f.write_char('\u{301}').unwrap();
+    f.write_str("\u{200d}").unwrap();
+    f.write_str("👍").unwrap();
+    assert_eq!(f.width, 3);
+
+    let s = Pad::right('.', 8, Display).measured(Measure::Columns);
+    assert_eq!(format!("日本" as s), "....日本");
+    assert_eq!(format!("👍🏽!" as s), ".....👍🏽!");
+    let s = Pad::center('-', 5, Display).measured(Measure::Graphemes);
+    assert_eq!(format!("cafe\u{301}" as s), "cafe\u{301}-");
+    let s = Pad::left('.', 6, Display);
+    assert_eq!(format!("日本" as s), "日本....");
+}
+
+#[test]
+fn pad_pattern() {
+    use super::{Formats, StyleExt};
+
+    let s = Pad::left(". ", 12, Display);
+    assert_eq!(format!("Intro" as s, 1), "Intro. . . .1");
+    assert_eq!(format!("Setup" as s.pad_right(' ', 14)), "  Setup. . . .");
+    assert_eq!(format!("Chapter 1" as s), "Chapter 1. .");
+
+    let s = Pad::right("-=", 7, Display);
+    assert_eq!(format!({ 12 } as s), "-=-=-12");
+    let s = Pad::center("-=", 9, Display);
+    assert_eq!(format!("abc" as s), "-=-abc-=-");
+    let s = Pad::center("<>", 8, Display);
+    assert_eq!(format!("abc" as s), "<>abc<><");
+
+    let s = Display.pad_right(String::from("ab"), 5);
+    assert_eq!(format!('x' as s), "ababx");
+    let s = Pad::right_mod("123", 4, Display);
+    assert_eq!(format!("abcde" as s), "123abcde");
+
+    let s = Pad {
+        align: Dir::Sign,
+        with: "0·",
+        count: 6,
+        kind: Kind::Full,
+        measure: Measure::Chars,
+        style: Display,
+    };
+    assert_eq!(format!({ -12 } as s), "-0·012");
+    assert_eq!(format!("" as s), "0·0·0·");
+
+    // wide fills are cut short by whole characters
+    let s = Pad::left("日本", 7, Display).measured(Measure::Columns);
+    assert_eq!(format!('x' as s), "x日本日");
+    let s = Pad::right("日", 4, Display).measured(Measure::Columns);
+    assert_eq!(format!("abc" as s), "abc");
+    assert_eq!(format!("ab" as s), "日ab");
+    assert_eq!(format!('a' as s), "日a");
+
+    // empty fills do nothing
+    let s = Pad::left("", 4, Display);
+    assert_eq!(format!('a' as s), "a");
+
+    let mut f = String::new();
+    Hex(false).pad_right(" .", 6).format(&mut f, &0xab).unwrap();
+    assert_eq!(f, " . .ab");
+}
+
+#[test]
+fn pad_single_pass() {
+    use core::cell::Cell;
+
+    use super::Formats;
+
+    // formats differently every time, like a timestamp would
+    struct Ticker<'a>(&'a Cell<usize>, usize);
+    impl Format<Display> for Ticker<'_> {
+        fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+            self.0.set(self.0.get() + 1);
+            for _ in 0..self.1 {
+                self.0.get().fmt(f, &Display)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    let calls = Cell::new(0);
+    let check = |style: Pad<Display>, len, expected: &str| {
+        calls.set(0);
+        let mut f = String::new();
+        style.format(&mut f, &Ticker(&calls, len)).unwrap();
+        assert_eq!(f, expected);
+        calls.get()
+    };
+
+    assert_eq!(check(Pad::left('.', 4, Display), 1, "1..."), 1);
+    assert_eq!(check(Pad::left_mod('.', 4, Display), 5, "11111..."), 1);
+    assert_eq!(check(Pad::right('.', 4, Display), 1, "...1"), 1);
+    assert_eq!(check(Pad::center('.', 4, Display), 2, ".11."), 1);
+
+    let sign = Pad {
+        align: Dir::Sign,
+        ..Pad::right('0', 4, Display)
+    };
+    assert_eq!(check(sign, 2, "0011"), 1);
+
+    // too long to buffer, so formatted twice
+    let (ones, twos) = ("1".repeat(200), "2".repeat(200));
+    let style = Pad::right('.', 201, Display);
+    assert_eq!(check(style, 200, &format!('.', twos)), 2);
+    let style = Pad::left('.', 201, Display);
+    assert_eq!(check(style, 200, &format!(ones, '.')), 1);
+}
+
+#[test]
+fn wrap() {
+    use super::{StyleExt, Wrap};
+
+    let text = "the quick brown fox jumps over the lazy dog";
+    let s = Wrap::new(16, Display);
+    assert_eq!(
+        format!(text as s),
+        "the quick brown\nfox jumps over\nthe lazy dog"
+    );
+
+    let s = Display.wrapped(16).indented(2).hanging(4);
+    assert_eq!(
+        format!(text as s),
+        "  the quick\n    brown fox\n    jumps over\n    the lazy dog"
+    );
+
+    // existing newlines and leading whitespace are kept, trailing isn't
+    let s = Wrap::new(10, Display).indented(1).hanging(3);
+    assert_eq!(
+        format!("usage:  restd [opts]   \n\n  -h, --help  show help\n" as s),
+        " usage:\n   restd\n   [opts]\n\n   -h,\n   --help\n   show\n   help\n"
+    );
+
+    // long words overflow, unless hyphenated
+    let s = Wrap::new(8, Display);
+    assert_eq!(
+        format!("a supercalifragilistic word" as s),
+        "a\nsupercalifragilistic\nword"
+    );
+    let s = s.hyphenated();
+    assert_eq!(
+        format!("a supercalifragilistic word" as s),
+        "a\nsuperca-\nlifragi-\nlistic\nword"
+    );
+    let s = s.hanging(2);
+    assert_eq!(format!("abcdefghijklmn" as s), "abcdefg-\n  hijkl-\n  mn");
+    assert_eq!(format!("abcdefgh" as s), "abcdefgh");
+
+    // words longer than the buffer are streamed, wrapping first only if what's
+    // been seen of them doesn't fit
+    let long = "x".repeat(70);
+    let text = format!("12345678901234 ", long);
+    let s = Wrap::new(75, Display);
+    assert_eq!(format!(text as s), format!("12345678901234", '\n', long));
+    let s = Wrap::new(100, Display);
+    assert_eq!(format!(text as s), text);
+    assert_eq!(format!({ &long } as s), long);
+
+    // no-break spaces are kept, and don't break
+    let s = Wrap::new(8, Display);
+    assert_eq!(format!("walk 10\u{a0}km" as s), "walk\n10\u{a0}km");
+    assert_eq!(
+        format!("1\u{202f}000\u{2007}m ok" as s),
+        "1\u{202f}000\u{2007}m\nok"
+    );
+
+    // wide characters take up two columns
+    let s = Wrap::new(6, Display);
+    assert_eq!(format!("日本 語です ok" as s), "日本\n語です\nok");
+
+    // output is the same however it's split up
+    struct Chunks(&'static [&'static str]);
+    impl Format<Display> for Chunks {
+        fn fmt(&self, f: &mut dyn Write, _: &Display) -> Result {
+            self.0.iter().try_for_each(|chunk| f.write_str(chunk))
+        }
+    }
+
+    let s = Wrap::new(6, Display).indented(1).hyphenated();
+    assert_eq!(format!("ab cdefghij k" as s), " ab\ncdefg-\nhij k");
+    assert_eq!(
+        format!({ Chunks(&["a", "b c", "def", "", "ghi", "j", " ", "k"]) } as s),
+        " ab\ncdefg-\nhij k"
+    );
+    let s = Wrap::new(4, Display).measured(Measure::Graphemes);
+    assert_eq!(
+        format!({ Chunks(&["cafe", "\u{301} ", "ok"]) } as s),
+        "cafe\u{301}\nok"
+    );
+}
@@ -0,0 +1,231 @@
+use super::{Error, Formats, Measure, Modified, Modifier, Result, Style, WidthCounter, Write};
+
+/// The most characters an ellipsis can have.
+const MAX_ELLIPSIS: usize = 8;
+
+/// Cut the data off after `max` characters, replacing the end with
+/// `ellipsis` if anything was cut; e.g. `hello world` truncated to 8 with `…`
+/// is `hello w…`.
+///
+/// The ellipsis counts towards `max`, so the output is never wider than
+/// `max`. If the ellipsis is longer than `max`, only its start is used.
+///
+/// The ellipsis can be at most 8 characters, as it's held back on the stack;
+/// [`Truncate::ellipsis`] rejects longer ones, and formatting with one is an
+/// error.
+///
+/// Output is streamed: only the last few characters (as many as are in the
+/// ellipsis) are held back, and the inner style is stopped as soon as the
+/// limit is passed.
+///
+/// Equivalent of the precision in [`core::fmt`] for strings (e.g. `{:.10}`),
+/// but for any style.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Truncate<S> {
+    /// The most characters to write, as counted by `measure`.
+    pub max: usize,
+
+    /// What to end the output with if anything was cut. May be empty, and
+    /// must be at most 8 characters.
+    pub ellipsis: &'static str,
+
+    /// How to measure the width of the data and the ellipsis.
+    pub measure: Measure,
+
+    /// The style being wrapped by this modifier.
+    pub style: S,
+}
+super::derive!(struct Truncate<S!> { max, ellipsis, measure, style });
+
+impl<S: Style> Truncate<S> {
+    /// Cut off after `max` characters, without an ellipsis.
+    ///
+    /// Shorthand for `Truncate { max, ellipsis: "", measure: Chars, style }`.
+    pub const fn new(max: usize, style: S) -> Self {
+        Self::ellipsis(max, "", style)
+    }
+
+    /// Cut off after `max` characters, ending with `ellipsis` if anything was
+    /// cut.
+    ///
+    /// Shorthand for `Truncate { max, ellipsis, measure: Chars, style }`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ellipsis` is longer than 8 characters; in a const context,
+    /// e.g. `const T: Truncate<Display> = Truncate::ellipsis(3, "[truncated]", Display);`,
+    /// this is a compile error.
+    pub const fn ellipsis(max: usize, ellipsis: &'static str, style: S) -> Self {
+        assert!(
+            char_count(ellipsis) <= MAX_ELLIPSIS,
+            "`Truncate` ellipsis must be at most 8 characters"
+        );
+        Self {
+            max,
+            ellipsis,
+            measure: Measure::Chars,
+            style,
+        }
+    }
+}
+
+impl<S> Truncate<S> {
+    /// Measure the width of the data using `measure`, e.g. to truncate to a
+    /// number of terminal columns.
+    pub fn measured(self, measure: Measure) -> Self {
+        Self { measure, ..self }
+    }
+}
+
+impl<S: Style> Style for Truncate<S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+
+impl<S: Style> Modifier for Truncate<S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        if char_count(self.ellipsis) > MAX_ELLIPSIS {
+            return Err(Error);
+        }
+
+        // as much of the ellipsis as fits
+        let mut counter = WidthCounter::new(self.measure);
+        let mut ellipsis = self.ellipsis;
+        for (i, ch) in self.ellipsis.char_indices() {
+            counter.push(ch);
+            if counter.width > self.max {
+                ellipsis = &self.ellipsis[..i];
+                break;
+            }
+        }
+        let hold = self.measure.of(ellipsis);
+
+        let mut f = Truncator {
+            f,
+            counter: WidthCounter::new(self.measure),
+            left: self.max - hold,
+            hold,
+            holding: false,
+            held: 0,
+            buf: [0; MAX_ELLIPSIS * 4],
+            len: 0,
+            ellipsis,
+            cut: false,
+        };
+
+        match self.style.format(&mut f, data) {
+            Err(_) if f.cut => Ok(()),
+            Err(e) => Err(e),
+            Ok(()) => {
+                // everything fit, so whatever was held back belongs to the data
+                let held = core::str::from_utf8(&f.buf[..f.len]).map_err(|_| Error)?;
+                f.f.write_str(held)
+            }
+        }
+    }
+}
+
+/// The number of characters in `s`, in a const context.
+const fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // every character has exactly one byte that isn't a continuation
+        if bytes[i] & 0xc0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+
+    count
+}
+
+/// Writes up to a limit, holding back the last `hold` characters until it's
+/// known whether or not the data fits.
+struct Truncator<'w> {
+    f: &'w mut dyn Write,
+    counter: WidthCounter,
+
+    /// Characters which can still be written directly.
+    left: usize,
+
+    /// The number of characters to hold back.
+    hold: usize,
+
+    /// Whether or not characters are being held back yet.
+    holding: bool,
+
+    /// The number of characters currently held back.
+    held: usize,
+
+    buf: [u8; MAX_ELLIPSIS * 4],
+    len: usize,
+
+    ellipsis: &'w str,
+
+    /// Whether or not the data has been cut off.
+    cut: bool,
+}
+
+impl Truncator<'_> {
+    fn hold_char(&mut self, ch: char, width: usize) -> Result {
+        // a long run of zero-width characters may not fit in the buffer, in
+        // which case cutting early is the only way to stay within the limit
+        if self.held + width > self.hold || self.len + ch.len_utf8() > self.buf.len() {
+            self.f.write_str(self.ellipsis)?;
+            self.cut = true;
+            return Err(Error);
+        }
+
+        ch.encode_utf8(&mut self.buf[self.len..]);
+        self.len += ch.len_utf8();
+        self.held += width;
+
+        Ok(())
+    }
+}
+
+impl Write for Truncator<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        if self.cut {
+            return Err(Error);
+        }
+
+        let mut later = data;
+        let mut measured = None;
+        if !self.holding {
+            let mut end = data.len();
+            for (i, ch) in data.char_indices() {
+                let width = self.counter.push(ch);
+                if width > self.left {
+                    self.holding = true;
+                    measured = Some(width);
+                    end = i;
+                    break;
+                }
+
+                self.left -= width;
+            }
+
+            self.f.write_str(&data[..end])?;
+            later = &data[end..];
+        }
+
+        for ch in later.chars() {
+            let width = measured.take().unwrap_or_else(|| self.counter.push(ch));
+            self.hold_char(ch, width)?;
+        }
+
+        Ok(())
+    }
+}
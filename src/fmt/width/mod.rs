@@ -0,0 +1,208 @@
+use super::{Result, Write};
+
+mod tables;
+
+const ZWJ: char = '\u{200d}';
+const VS16: char = '\u{fe0f}';
+
+/// How to measure the width of text, e.g. when [padding](super::Pad).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Measure {
+    /// Count `char`s. Fast, but misaligns text containing combining marks,
+    /// emoji, or East Asian characters.
+    #[default]
+    Chars,
+
+    /// Count grapheme clusters, i.e. user-perceived characters; e.g. an `e`
+    /// followed by a combining accent is one.
+    Graphemes,
+
+    /// Count the columns a terminal would use: like
+    /// [`Graphemes`](Measure::Graphemes), but East Asian wide characters and
+    /// emoji take up two, and control characters take up none.
+    Columns,
+}
+super::derive!(
+    enum Measure {
+        Chars,
+        Graphemes,
+        Columns,
+    }
+);
+
+impl Measure {
+    /// Measure the width of a string.
+    pub fn of(self, data: &str) -> usize {
+        let mut counter = WidthCounter::new(self);
+        counter.push_str(data);
+        counter.width
+    }
+}
+
+/// A sink that measures the width of everything written to it, according to a
+/// [`Measure`].
+///
+/// Doesn't actually write any data anywhere.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WidthCounter {
+    /// The width measured so far.
+    pub width: usize,
+
+    measure: Measure,
+    seg: Segmenter,
+}
+
+impl WidthCounter {
+    /// Create a new, empty counter.
+    pub fn new(measure: Measure) -> Self {
+        Self {
+            width: 0,
+            measure,
+            seg: Segmenter::default(),
+        }
+    }
+
+    /// Measure a character, returning how much it added to the width.
+    ///
+    /// Characters which join the previous grapheme cluster usually add
+    /// nothing.
+    pub fn push(&mut self, ch: char) -> usize {
+        let added = match self.measure {
+            Measure::Chars => 1,
+            Measure::Graphemes => self.seg.push(ch).starts as usize,
+            Measure::Columns => self.seg.push(ch).columns,
+        };
+
+        self.width += added;
+        added
+    }
+
+    fn push_str(&mut self, data: &str) {
+        if self.measure == Measure::Chars {
+            self.width += data.chars().count();
+            return;
+        }
+
+        for ch in data.chars() {
+            self.push(ch);
+        }
+    }
+}
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, data: &str) -> Result {
+        self.push_str(data);
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> Result {
+        self.push(ch);
+        Ok(())
+    }
+}
+
+/// The class of a character, as far as width and segmentation are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    /// Takes up no columns, and starts a new grapheme cluster.
+    Zero,
+
+    /// Joins the previous grapheme cluster, taking up no columns.
+    Extend,
+
+    /// Takes up two columns.
+    Wide,
+
+    /// A regional indicator; pairs of them form a flag.
+    Regional,
+}
+
+fn class(ch: char) -> Option<Class> {
+    tables::CLASSES
+        .binary_search_by(|&(lo, hi, _)| {
+            if hi < ch {
+                core::cmp::Ordering::Less
+            } else if lo > ch {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| tables::CLASSES[i].2)
+}
+
+/// What a character did to the width.
+struct Step {
+    /// Whether or not it started a new grapheme cluster.
+    starts: bool,
+
+    /// How many columns it added.
+    columns: usize,
+}
+
+/// Splits a stream of characters into grapheme clusters.
+///
+/// A simplification of UAX #29: clusters are broken everywhere except before
+/// extending characters (including spacing marks, ZWJ, and conjoining Hangul
+/// vowels and finals), after a ZWJ, between pairs of regional indicators, and
+/// between CR and LF.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Segmenter {
+    /// Whether or not anything has been seen yet.
+    started: bool,
+
+    /// Whether or not the last character was a ZWJ.
+    zwj: bool,
+
+    /// Whether or not the last character was a CR.
+    cr: bool,
+
+    /// Whether or not the last character was an unpaired regional indicator.
+    regional: bool,
+
+    /// The number of columns the current cluster takes up.
+    columns: usize,
+}
+
+impl Segmenter {
+    fn push(&mut self, ch: char) -> Step {
+        let class = class(ch);
+        let joins = self.started
+            && (class == Some(Class::Extend)
+                || self.zwj
+                || (self.regional && class == Some(Class::Regional))
+                || (self.cr && ch == '\n'));
+
+        self.started = true;
+        self.zwj = ch == ZWJ;
+        self.cr = ch == '\r';
+        self.regional = !joins && class == Some(Class::Regional);
+
+        if joins {
+            // emoji presentation makes narrow symbols (e.g. `❤`) wide
+            let columns = if ch == VS16 && self.columns == 1 {
+                1
+            } else {
+                0
+            };
+            self.columns += columns;
+
+            return Step {
+                starts: false,
+                columns,
+            };
+        }
+
+        self.columns = match class {
+            None => 1,
+            Some(Class::Wide | Class::Regional) => 2,
+            Some(Class::Zero | Class::Extend) => 0,
+        };
+
+        Step {
+            starts: true,
+            columns: self.columns,
+        }
+    }
+}
@@ -0,0 +1,461 @@
+// Generated by `scripts/width_tables.py` from Unicode 14.0.0; do not edit.
+
+use super::Class::{self, *};
+
+/// Inclusive ranges of characters and their classes, sorted.
+/// Characters not listed are one column wide.
+#[rustfmt::skip]
+pub(super) static CLASSES: &[(char, char, Class)] = &[
+    ('\u{0}', '\u{1f}', Zero),
+    ('\u{7f}', '\u{9f}', Zero),
+    ('\u{ad}', '\u{ad}', Zero),
+    ('\u{300}', '\u{36f}', Extend),
+    ('\u{483}', '\u{489}', Extend),
+    ('\u{591}', '\u{5bd}', Extend),
+    ('\u{5bf}', '\u{5bf}', Extend),
+    ('\u{5c1}', '\u{5c2}', Extend),
+    ('\u{5c4}', '\u{5c5}', Extend),
+    ('\u{5c7}', '\u{5c7}', Extend),
+    ('\u{600}', '\u{605}', Zero),
+    ('\u{610}', '\u{61a}', Extend),
+    ('\u{61c}', '\u{61c}', Zero),
+    ('\u{64b}', '\u{65f}', Extend),
+    ('\u{670}', '\u{670}', Extend),
+    ('\u{6d6}', '\u{6dc}', Extend),
+    ('\u{6dd}', '\u{6dd}', Zero),
+    ('\u{6df}', '\u{6e4}', Extend),
+    ('\u{6e7}', '\u{6e8}', Extend),
+    ('\u{6ea}', '\u{6ed}', Extend),
+    ('\u{70f}', '\u{70f}', Zero),
+    ('\u{711}', '\u{711}', Extend),
+    ('\u{730}', '\u{74a}', Extend),
+    ('\u{7a6}', '\u{7b0}', Extend),
+    ('\u{7eb}', '\u{7f3}', Extend),
+    ('\u{7fd}', '\u{7fd}', Extend),
+    ('\u{816}', '\u{819}', Extend),
+    ('\u{81b}', '\u{823}', Extend),
+    ('\u{825}', '\u{827}', Extend),
+    ('\u{829}', '\u{82d}', Extend),
+    ('\u{859}', '\u{85b}', Extend),
+    ('\u{890}', '\u{891}', Zero),
+    ('\u{898}', '\u{89f}', Extend),
+    ('\u{8ca}', '\u{8e1}', Extend),
+    ('\u{8e2}', '\u{8e2}', Zero),
+    ('\u{8e3}', '\u{903}', Extend),
+    ('\u{93a}', '\u{93c}', Extend),
+    ('\u{93e}', '\u{94f}', Extend),
+    ('\u{951}', '\u{957}', Extend),
+    ('\u{962}', '\u{963}', Extend),
+    ('\u{981}', '\u{983}', Extend),
+    ('\u{9bc}', '\u{9bc}', Extend),
+    ('\u{9be}', '\u{9c4}', Extend),
+    ('\u{9c7}', '\u{9c8}', Extend),
+    ('\u{9cb}', '\u{9cd}', Extend),
+    ('\u{9d7}', '\u{9d7}', Extend),
+    ('\u{9e2}', '\u{9e3}', Extend),
+    ('\u{9fe}', '\u{9fe}', Extend),
+    ('\u{a01}', '\u{a03}', Extend),
+    ('\u{a3c}', '\u{a3c}', Extend),
+    ('\u{a3e}', '\u{a42}', Extend),
+    ('\u{a47}', '\u{a48}', Extend),
+    ('\u{a4b}', '\u{a4d}', Extend),
+    ('\u{a51}', '\u{a51}', Extend),
+    ('\u{a70}', '\u{a71}', Extend),
+    ('\u{a75}', '\u{a75}', Extend),
+    ('\u{a81}', '\u{a83}', Extend),
+    ('\u{abc}', '\u{abc}', Extend),
+    ('\u{abe}', '\u{ac5}', Extend),
+    ('\u{ac7}', '\u{ac9}', Extend),
+    ('\u{acb}', '\u{acd}', Extend),
+    ('\u{ae2}', '\u{ae3}', Extend),
+    ('\u{afa}', '\u{aff}', Extend),
+    ('\u{b01}', '\u{b03}', Extend),
+    ('\u{b3c}', '\u{b3c}', Extend),
+    ('\u{b3e}', '\u{b44}', Extend),
+    ('\u{b47}', '\u{b48}', Extend),
+    ('\u{b4b}', '\u{b4d}', Extend),
+    ('\u{b55}', '\u{b57}', Extend),
+    ('\u{b62}', '\u{b63}', Extend),
+    ('\u{b82}', '\u{b82}', Extend),
+    ('\u{bbe}', '\u{bc2}', Extend),
+    ('\u{bc6}', '\u{bc8}', Extend),
+    ('\u{bca}', '\u{bcd}', Extend),
+    ('\u{bd7}', '\u{bd7}', Extend),
+    ('\u{c00}', '\u{c04}', Extend),
+    ('\u{c3c}', '\u{c3c}', Extend),
+    ('\u{c3e}', '\u{c44}', Extend),
+    ('\u{c46}', '\u{c48}', Extend),
+    ('\u{c4a}', '\u{c4d}', Extend),
+    ('\u{c55}', '\u{c56}', Extend),
+    ('\u{c62}', '\u{c63}', Extend),
+    ('\u{c81}', '\u{c83}', Extend),
+    ('\u{cbc}', '\u{cbc}', Extend),
+    ('\u{cbe}', '\u{cc4}', Extend),
+    ('\u{cc6}', '\u{cc8}', Extend),
+    ('\u{cca}', '\u{ccd}', Extend),
+    ('\u{cd5}', '\u{cd6}', Extend),
+    ('\u{ce2}', '\u{ce3}', Extend),
+    ('\u{d00}', '\u{d03}', Extend),
+    ('\u{d3b}', '\u{d3c}', Extend),
+    ('\u{d3e}', '\u{d44}', Extend),
+    ('\u{d46}', '\u{d48}', Extend),
+    ('\u{d4a}', '\u{d4d}', Extend),
+    ('\u{d57}', '\u{d57}', Extend),
+    ('\u{d62}', '\u{d63}', Extend),
+    ('\u{d81}', '\u{d83}', Extend),
+    ('\u{dca}', '\u{dca}', Extend),
+    ('\u{dcf}', '\u{dd4}', Extend),
+    ('\u{dd6}', '\u{dd6}', Extend),
+    ('\u{dd8}', '\u{ddf}', Extend),
+    ('\u{df2}', '\u{df3}', Extend),
+    ('\u{e31}', '\u{e31}', Extend),
+    ('\u{e34}', '\u{e3a}', Extend),
+    ('\u{e47}', '\u{e4e}', Extend),
+    ('\u{eb1}', '\u{eb1}', Extend),
+    ('\u{eb4}', '\u{ebc}', Extend),
+    ('\u{ec8}', '\u{ecd}', Extend),
+    ('\u{f18}', '\u{f19}', Extend),
+    ('\u{f35}', '\u{f35}', Extend),
+    ('\u{f37}', '\u{f37}', Extend),
+    ('\u{f39}', '\u{f39}', Extend),
+    ('\u{f3e}', '\u{f3f}', Extend),
+    ('\u{f71}', '\u{f84}', Extend),
+    ('\u{f86}', '\u{f87}', Extend),
+    ('\u{f8d}', '\u{f97}', Extend),
+    ('\u{f99}', '\u{fbc}', Extend),
+    ('\u{fc6}', '\u{fc6}', Extend),
+    ('\u{102b}', '\u{103e}', Extend),
+    ('\u{1056}', '\u{1059}', Extend),
+    ('\u{105e}', '\u{1060}', Extend),
+    ('\u{1062}', '\u{1064}', Extend),
+    ('\u{1067}', '\u{106d}', Extend),
+    ('\u{1071}', '\u{1074}', Extend),
+    ('\u{1082}', '\u{108d}', Extend),
+    ('\u{108f}', '\u{108f}', Extend),
+    ('\u{109a}', '\u{109d}', Extend),
+    ('\u{1100}', '\u{115f}', Wide),
+    ('\u{1160}', '\u{11ff}', Extend),
+    ('\u{135d}', '\u{135f}', Extend),
+    ('\u{1712}', '\u{1715}', Extend),
+    ('\u{1732}', '\u{1734}', Extend),
+    ('\u{1752}', '\u{1753}', Extend),
+    ('\u{1772}', '\u{1773}', Extend),
+    ('\u{17b4}', '\u{17d3}', Extend),
+    ('\u{17dd}', '\u{17dd}', Extend),
+    ('\u{180b}', '\u{180d}', Extend),
+    ('\u{180e}', '\u{180e}', Zero),
+    ('\u{180f}', '\u{180f}', Extend),
+    ('\u{1885}', '\u{1886}', Extend),
+    ('\u{18a9}', '\u{18a9}', Extend),
+    ('\u{1920}', '\u{192b}', Extend),
+    ('\u{1930}', '\u{193b}', Extend),
+    ('\u{1a17}', '\u{1a1b}', Extend),
+    ('\u{1a55}', '\u{1a5e}', Extend),
+    ('\u{1a60}', '\u{1a7c}', Extend),
+    ('\u{1a7f}', '\u{1a7f}', Extend),
+    ('\u{1ab0}', '\u{1ace}', Extend),
+    ('\u{1b00}', '\u{1b04}', Extend),
+    ('\u{1b34}', '\u{1b44}', Extend),
+    ('\u{1b6b}', '\u{1b73}', Extend),
+    ('\u{1b80}', '\u{1b82}', Extend),
+    ('\u{1ba1}', '\u{1bad}', Extend),
+    ('\u{1be6}', '\u{1bf3}', Extend),
+    ('\u{1c24}', '\u{1c37}', Extend),
+    ('\u{1cd0}', '\u{1cd2}', Extend),
+    ('\u{1cd4}', '\u{1ce8}', Extend),
+    ('\u{1ced}', '\u{1ced}', Extend),
+    ('\u{1cf4}', '\u{1cf4}', Extend),
+    ('\u{1cf7}', '\u{1cf9}', Extend),
+    ('\u{1dc0}', '\u{1dff}', Extend),
+    ('\u{200b}', '\u{200b}', Zero),
+    ('\u{200c}', '\u{200d}', Extend),
+    ('\u{200e}', '\u{200f}', Zero),
+    ('\u{2028}', '\u{202e}', Zero),
+    ('\u{2060}', '\u{2064}', Zero),
+    ('\u{2066}', '\u{206f}', Zero),
+    ('\u{20d0}', '\u{20f0}', Extend),
+    ('\u{231a}', '\u{231b}', Wide),
+    ('\u{2329}', '\u{232a}', Wide),
+    ('\u{23e9}', '\u{23ec}', Wide),
+    ('\u{23f0}', '\u{23f0}', Wide),
+    ('\u{23f3}', '\u{23f3}', Wide),
+    ('\u{25fd}', '\u{25fe}', Wide),
+    ('\u{2614}', '\u{2615}', Wide),
+    ('\u{2648}', '\u{2653}', Wide),
+    ('\u{267f}', '\u{267f}', Wide),
+    ('\u{2693}', '\u{2693}', Wide),
+    ('\u{26a1}', '\u{26a1}', Wide),
+    ('\u{26aa}', '\u{26ab}', Wide),
+    ('\u{26bd}', '\u{26be}', Wide),
+    ('\u{26c4}', '\u{26c5}', Wide),
+    ('\u{26ce}', '\u{26ce}', Wide),
+    ('\u{26d4}', '\u{26d4}', Wide),
+    ('\u{26ea}', '\u{26ea}', Wide),
+    ('\u{26f2}', '\u{26f3}', Wide),
+    ('\u{26f5}', '\u{26f5}', Wide),
+    ('\u{26fa}', '\u{26fa}', Wide),
+    ('\u{26fd}', '\u{26fd}', Wide),
+    ('\u{2705}', '\u{2705}', Wide),
+    ('\u{270a}', '\u{270b}', Wide),
+    ('\u{2728}', '\u{2728}', Wide),
+    ('\u{274c}', '\u{274c}', Wide),
+    ('\u{274e}', '\u{274e}', Wide),
+    ('\u{2753}', '\u{2755}', Wide),
+    ('\u{2757}', '\u{2757}', Wide),
+    ('\u{2795}', '\u{2797}', Wide),
+    ('\u{27b0}', '\u{27b0}', Wide),
+    ('\u{27bf}', '\u{27bf}', Wide),
+    ('\u{2b1b}', '\u{2b1c}', Wide),
+    ('\u{2b50}', '\u{2b50}', Wide),
+    ('\u{2b55}', '\u{2b55}', Wide),
+    ('\u{2cef}', '\u{2cf1}', Extend),
+    ('\u{2d7f}', '\u{2d7f}', Extend),
+    ('\u{2de0}', '\u{2dff}', Extend),
+    ('\u{2e80}', '\u{2e99}', Wide),
+    ('\u{2e9b}', '\u{2ef3}', Wide),
+    ('\u{2f00}', '\u{2fd5}', Wide),
+    ('\u{2ff0}', '\u{2ffb}', Wide),
+    ('\u{3000}', '\u{3029}', Wide),
+    ('\u{302a}', '\u{302f}', Extend),
+    ('\u{3030}', '\u{303e}', Wide),
+    ('\u{3041}', '\u{3096}', Wide),
+    ('\u{3099}', '\u{309a}', Extend),
+    ('\u{309b}', '\u{30ff}', Wide),
+    ('\u{3105}', '\u{312f}', Wide),
+    ('\u{3131}', '\u{318e}', Wide),
+    ('\u{3190}', '\u{31e3}', Wide),
+    ('\u{31f0}', '\u{321e}', Wide),
+    ('\u{3220}', '\u{3247}', Wide),
+    ('\u{3250}', '\u{4dbf}', Wide),
+    ('\u{4e00}', '\u{a48c}', Wide),
+    ('\u{a490}', '\u{a4c6}', Wide),
+    ('\u{a66f}', '\u{a672}', Extend),
+    ('\u{a674}', '\u{a67d}', Extend),
+    ('\u{a69e}', '\u{a69f}', Extend),
+    ('\u{a6f0}', '\u{a6f1}', Extend),
+    ('\u{a802}', '\u{a802}', Extend),
+    ('\u{a806}', '\u{a806}', Extend),
+    ('\u{a80b}', '\u{a80b}', Extend),
+    ('\u{a823}', '\u{a827}', Extend),
+    ('\u{a82c}', '\u{a82c}', Extend),
+    ('\u{a880}', '\u{a881}', Extend),
+    ('\u{a8b4}', '\u{a8c5}', Extend),
+    ('\u{a8e0}', '\u{a8f1}', Extend),
+    ('\u{a8ff}', '\u{a8ff}', Extend),
+    ('\u{a926}', '\u{a92d}', Extend),
+    ('\u{a947}', '\u{a953}', Extend),
+    ('\u{a960}', '\u{a97c}', Wide),
+    ('\u{a980}', '\u{a983}', Extend),
+    ('\u{a9b3}', '\u{a9c0}', Extend),
+    ('\u{a9e5}', '\u{a9e5}', Extend),
+    ('\u{aa29}', '\u{aa36}', Extend),
+    ('\u{aa43}', '\u{aa43}', Extend),
+    ('\u{aa4c}', '\u{aa4d}', Extend),
+    ('\u{aa7b}', '\u{aa7d}', Extend),
+    ('\u{aab0}', '\u{aab0}', Extend),
+    ('\u{aab2}', '\u{aab4}', Extend),
+    ('\u{aab7}', '\u{aab8}', Extend),
+    ('\u{aabe}', '\u{aabf}', Extend),
+    ('\u{aac1}', '\u{aac1}', Extend),
+    ('\u{aaeb}', '\u{aaef}', Extend),
+    ('\u{aaf5}', '\u{aaf6}', Extend),
+    ('\u{abe3}', '\u{abea}', Extend),
+    ('\u{abec}', '\u{abed}', Extend),
+    ('\u{ac00}', '\u{d7a3}', Wide),
+    ('\u{d7b0}', '\u{d7c6}', Extend),
+    ('\u{d7cb}', '\u{d7fb}', Extend),
+    ('\u{f900}', '\u{fa6d}', Wide),
+    ('\u{fa70}', '\u{fad9}', Wide),
+    ('\u{fb1e}', '\u{fb1e}', Extend),
+    ('\u{fe00}', '\u{fe0f}', Extend),
+    ('\u{fe10}', '\u{fe19}', Wide),
+    ('\u{fe20}', '\u{fe2f}', Extend),
+    ('\u{fe30}', '\u{fe52}', Wide),
+    ('\u{fe54}', '\u{fe66}', Wide),
+    ('\u{fe68}', '\u{fe6b}', Wide),
+    ('\u{feff}', '\u{feff}', Zero),
+    ('\u{ff01}', '\u{ff60}', Wide),
+    ('\u{ffe0}', '\u{ffe6}', Wide),
+    ('\u{fff9}', '\u{fffb}', Zero),
+    ('\u{101fd}', '\u{101fd}', Extend),
+    ('\u{102e0}', '\u{102e0}', Extend),
+    ('\u{10376}', '\u{1037a}', Extend),
+    ('\u{10a01}', '\u{10a03}', Extend),
+    ('\u{10a05}', '\u{10a06}', Extend),
+    ('\u{10a0c}', '\u{10a0f}', Extend),
+    ('\u{10a38}', '\u{10a3a}', Extend),
+    ('\u{10a3f}', '\u{10a3f}', Extend),
+    ('\u{10ae5}', '\u{10ae6}', Extend),
+    ('\u{10d24}', '\u{10d27}', Extend),
+    ('\u{10eab}', '\u{10eac}', Extend),
+    ('\u{10f46}', '\u{10f50}', Extend),
+    ('\u{10f82}', '\u{10f85}', Extend),
+    ('\u{11000}', '\u{11002}', Extend),
+    ('\u{11038}', '\u{11046}', Extend),
+    ('\u{11070}', '\u{11070}', Extend),
+    ('\u{11073}', '\u{11074}', Extend),
+    ('\u{1107f}', '\u{11082}', Extend),
+    ('\u{110b0}', '\u{110ba}', Extend),
+    ('\u{110bd}', '\u{110bd}', Zero),
+    ('\u{110c2}', '\u{110c2}', Extend),
+    ('\u{110cd}', '\u{110cd}', Zero),
+    ('\u{11100}', '\u{11102}', Extend),
+    ('\u{11127}', '\u{11134}', Extend),
+    ('\u{11145}', '\u{11146}', Extend),
+    ('\u{11173}', '\u{11173}', Extend),
+    ('\u{11180}', '\u{11182}', Extend),
+    ('\u{111b3}', '\u{111c0}', Extend),
+    ('\u{111c9}', '\u{111cc}', Extend),
+    ('\u{111ce}', '\u{111cf}', Extend),
+    ('\u{1122c}', '\u{11237}', Extend),
+    ('\u{1123e}', '\u{1123e}', Extend),
+    ('\u{112df}', '\u{112ea}', Extend),
+    ('\u{11300}', '\u{11303}', Extend),
+    ('\u{1133b}', '\u{1133c}', Extend),
+    ('\u{1133e}', '\u{11344}', Extend),
+    ('\u{11347}', '\u{11348}', Extend),
+    ('\u{1134b}', '\u{1134d}', Extend),
+    ('\u{11357}', '\u{11357}', Extend),
+    ('\u{11362}', '\u{11363}', Extend),
+    ('\u{11366}', '\u{1136c}', Extend),
+    ('\u{11370}', '\u{11374}', Extend),
+    ('\u{11435}', '\u{11446}', Extend),
+    ('\u{1145e}', '\u{1145e}', Extend),
+    ('\u{114b0}', '\u{114c3}', Extend),
+    ('\u{115af}', '\u{115b5}', Extend),
+    ('\u{115b8}', '\u{115c0}', Extend),
+    ('\u{115dc}', '\u{115dd}', Extend),
+    ('\u{11630}', '\u{11640}', Extend),
+    ('\u{116ab}', '\u{116b7}', Extend),
+    ('\u{1171d}', '\u{1172b}', Extend),
+    ('\u{1182c}', '\u{1183a}', Extend),
+    ('\u{11930}', '\u{11935}', Extend),
+    ('\u{11937}', '\u{11938}', Extend),
+    ('\u{1193b}', '\u{1193e}', Extend),
+    ('\u{11940}', '\u{11940}', Extend),
+    ('\u{11942}', '\u{11943}', Extend),
+    ('\u{119d1}', '\u{119d7}', Extend),
+    ('\u{119da}', '\u{119e0}', Extend),
+    ('\u{119e4}', '\u{119e4}', Extend),
+    ('\u{11a01}', '\u{11a0a}', Extend),
+    ('\u{11a33}', '\u{11a39}', Extend),
+    ('\u{11a3b}', '\u{11a3e}', Extend),
+    ('\u{11a47}', '\u{11a47}', Extend),
+    ('\u{11a51}', '\u{11a5b}', Extend),
+    ('\u{11a8a}', '\u{11a99}', Extend),
+    ('\u{11c2f}', '\u{11c36}', Extend),
+    ('\u{11c38}', '\u{11c3f}', Extend),
+    ('\u{11c92}', '\u{11ca7}', Extend),
+    ('\u{11ca9}', '\u{11cb6}', Extend),
+    ('\u{11d31}', '\u{11d36}', Extend),
+    ('\u{11d3a}', '\u{11d3a}', Extend),
+    ('\u{11d3c}', '\u{11d3d}', Extend),
+    ('\u{11d3f}', '\u{11d45}', Extend),
+    ('\u{11d47}', '\u{11d47}', Extend),
+    ('\u{11d8a}', '\u{11d8e}', Extend),
+    ('\u{11d90}', '\u{11d91}', Extend),
+    ('\u{11d93}', '\u{11d97}', Extend),
+    ('\u{11ef3}', '\u{11ef6}', Extend),
+    ('\u{13430}', '\u{13438}', Zero),
+    ('\u{16af0}', '\u{16af4}', Extend),
+    ('\u{16b30}', '\u{16b36}', Extend),
+    ('\u{16f4f}', '\u{16f4f}', Extend),
+    ('\u{16f51}', '\u{16f87}', Extend),
+    ('\u{16f8f}', '\u{16f92}', Extend),
+    ('\u{16fe0}', '\u{16fe3}', Wide),
+    ('\u{16fe4}', '\u{16fe4}', Extend),
+    ('\u{16ff0}', '\u{16ff1}', Extend),
+    ('\u{17000}', '\u{187f7}', Wide),
+    ('\u{18800}', '\u{18cd5}', Wide),
+    ('\u{18d00}', '\u{18d08}', Wide),
+    ('\u{1aff0}', '\u{1aff3}', Wide),
+    ('\u{1aff5}', '\u{1affb}', Wide),
+    ('\u{1affd}', '\u{1affe}', Wide),
+    ('\u{1b000}', '\u{1b122}', Wide),
+    ('\u{1b150}', '\u{1b152}', Wide),
+    ('\u{1b164}', '\u{1b167}', Wide),
+    ('\u{1b170}', '\u{1b2fb}', Wide),
+    ('\u{1bc9d}', '\u{1bc9e}', Extend),
+    ('\u{1bca0}', '\u{1bca3}', Zero),
+    ('\u{1cf00}', '\u{1cf2d}', Extend),
+    ('\u{1cf30}', '\u{1cf46}', Extend),
+    ('\u{1d165}', '\u{1d169}', Extend),
+    ('\u{1d16d}', '\u{1d172}', Extend),
+    ('\u{1d173}', '\u{1d17a}', Zero),
+    ('\u{1d17b}', '\u{1d182}', Extend),
+    ('\u{1d185}', '\u{1d18b}', Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', Extend),
+    ('\u{1d242}', '\u{1d244}', Extend),
+    ('\u{1da00}', '\u{1da36}', Extend),
+    ('\u{1da3b}', '\u{1da6c}', Extend),
+    ('\u{1da75}', '\u{1da75}', Extend),
+    ('\u{1da84}', '\u{1da84}', Extend),
+    ('\u{1da9b}', '\u{1da9f}', Extend),
+    ('\u{1daa1}', '\u{1daaf}', Extend),
+    ('\u{1e000}', '\u{1e006}', Extend),
+    ('\u{1e008}', '\u{1e018}', Extend),
+    ('\u{1e01b}', '\u{1e021}', Extend),
+    ('\u{1e023}', '\u{1e024}', Extend),
+    ('\u{1e026}', '\u{1e02a}', Extend),
+    ('\u{1e130}', '\u{1e136}', Extend),
+    ('\u{1e2ae}', '\u{1e2ae}', Extend),
+    ('\u{1e2ec}', '\u{1e2ef}', Extend),
+    ('\u{1e8d0}', '\u{1e8d6}', Extend),
+    ('\u{1e944}', '\u{1e94a}', Extend),
+    ('\u{1f004}', '\u{1f004}', Wide),
+    ('\u{1f0cf}', '\u{1f0cf}', Wide),
+    ('\u{1f18e}', '\u{1f18e}', Wide),
+    ('\u{1f191}', '\u{1f19a}', Wide),
+    ('\u{1f1e6}', '\u{1f1ff}', Regional),
+    ('\u{1f200}', '\u{1f202}', Wide),
+    ('\u{1f210}', '\u{1f23b}', Wide),
+    ('\u{1f240}', '\u{1f248}', Wide),
+    ('\u{1f250}', '\u{1f251}', Wide),
+    ('\u{1f260}', '\u{1f265}', Wide),
+    ('\u{1f300}', '\u{1f320}', Wide),
+    ('\u{1f32d}', '\u{1f335}', Wide),
+    ('\u{1f337}', '\u{1f37c}', Wide),
+    ('\u{1f37e}', '\u{1f393}', Wide),
+    ('\u{1f3a0}', '\u{1f3ca}', Wide),
+    ('\u{1f3cf}', '\u{1f3d3}', Wide),
+    ('\u{1f3e0}', '\u{1f3f0}', Wide),
+    ('\u{1f3f4}', '\u{1f3f4}', Wide),
+    ('\u{1f3f8}', '\u{1f3fa}', Wide),
+    ('\u{1f3fb}', '\u{1f3ff}', Extend),
+    ('\u{1f400}', '\u{1f43e}', Wide),
+    ('\u{1f440}', '\u{1f440}', Wide),
+    ('\u{1f442}', '\u{1f4fc}', Wide),
+    ('\u{1f4ff}', '\u{1f53d}', Wide),
+    ('\u{1f54b}', '\u{1f54e}', Wide),
+    ('\u{1f550}', '\u{1f567}', Wide),
+    ('\u{1f57a}', '\u{1f57a}', Wide),
+    ('\u{1f595}', '\u{1f596}', Wide),
+    ('\u{1f5a4}', '\u{1f5a4}', Wide),
+    ('\u{1f5fb}', '\u{1f64f}', Wide),
+    ('\u{1f680}', '\u{1f6c5}', Wide),
+    ('\u{1f6cc}', '\u{1f6cc}', Wide),
+    ('\u{1f6d0}', '\u{1f6d2}', Wide),
+    ('\u{1f6d5}', '\u{1f6d7}', Wide),
+    ('\u{1f6dd}', '\u{1f6df}', Wide),
+    ('\u{1f6eb}', '\u{1f6ec}', Wide),
+    ('\u{1f6f4}', '\u{1f6fc}', Wide),
+    ('\u{1f7e0}', '\u{1f7eb}', Wide),
+    ('\u{1f7f0}', '\u{1f7f0}', Wide),
+    ('\u{1f90c}', '\u{1f93a}', Wide),
+    ('\u{1f93c}', '\u{1f945}', Wide),
+    ('\u{1f947}', '\u{1f9ff}', Wide),
+    ('\u{1fa70}', '\u{1fa74}', Wide),
+    ('\u{1fa78}', '\u{1fa7c}', Wide),
+    ('\u{1fa80}', '\u{1fa86}', Wide),
+    ('\u{1fa90}', '\u{1faac}', Wide),
+    ('\u{1fab0}', '\u{1faba}', Wide),
+    ('\u{1fac0}', '\u{1fac5}', Wide),
+    ('\u{1fad0}', '\u{1fad9}', Wide),
+    ('\u{1fae0}', '\u{1fae7}', Wide),
+    ('\u{1faf0}', '\u{1faf6}', Wide),
+    ('\u{20000}', '\u{2fffd}', Wide),
+    ('\u{30000}', '\u{3fffd}', Wide),
+    ('\u{e0001}', '\u{e0001}', Zero),
+    ('\u{e0020}', '\u{e007f}', Extend),
+    ('\u{e0100}', '\u{e01ef}', Extend),
+];
@@ -0,0 +1,323 @@
+use super::{Error, Formats, Measure, Modified, Modifier, Result, Style, WidthCounter, Write};
+
+/// Wrap the data into lines at most `width` wide, breaking on whitespace other
+/// than no-break spaces (U+00A0, U+2007, and U+202F).
+///
+/// The first line, and the first line after every newline in the data, is
+/// indented by `indent` spaces; lines created by wrapping are indented by
+/// `hang` spaces instead. Whitespace at the start of a line in the data is
+/// kept, but whitespace where a line is wrapped, and at the end of a line, is
+/// dropped. Tabs are written as a single space.
+///
+/// Words too long to fit on a line of their own are written anyway, or broken
+/// across lines with a `-` if `hyphenate` is set.
+///
+/// Output is streamed, one word at a time. Words over 64 bytes long are
+/// written before their full width is known, so they're only started on a new
+/// line if their first 64 bytes don't fit, and may otherwise run past `width`
+/// unless hyphenated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Wrap<S> {
+    /// The most columns a line may take up, including indentation.
+    pub width: usize,
+
+    /// The indentation of the first line of each paragraph.
+    pub indent: usize,
+
+    /// The indentation of lines created by wrapping.
+    pub hang: usize,
+
+    /// Whether or not to break words too long for a line with a `-`.
+    pub hyphenate: bool,
+
+    /// How to measure the width of the data.
+    pub measure: Measure,
+
+    /// The style being wrapped by this modifier.
+    pub style: S,
+}
+super::derive!(struct Wrap<S!> { width, indent, hang, hyphenate, measure, style });
+
+impl<S: Style> Wrap<S> {
+    /// Wrap to `width` columns, without indentation or hyphenation.
+    ///
+    /// Shorthand for
+    /// `Wrap { width, indent: 0, hang: 0, hyphenate: false, measure: Columns, style }`.
+    pub const fn new(width: usize, style: S) -> Self {
+        Self {
+            width,
+            indent: 0,
+            hang: 0,
+            hyphenate: false,
+            measure: Measure::Columns,
+            style,
+        }
+    }
+}
+
+impl<S> Wrap<S> {
+    /// Indent the first line of each paragraph by `indent` spaces.
+    pub fn indented(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+
+    /// Indent lines created by wrapping by `hang` spaces.
+    pub fn hanging(self, hang: usize) -> Self {
+        Self { hang, ..self }
+    }
+
+    /// Break words too long for a line with a `-`.
+    pub fn hyphenated(self) -> Self {
+        Self {
+            hyphenate: true,
+            ..self
+        }
+    }
+
+    /// Measure the width of the data using `measure`.
+    pub fn measured(self, measure: Measure) -> Self {
+        Self { measure, ..self }
+    }
+}
+
+impl<S: Style> Style for Wrap<S> {
+    type Role = Modified;
+
+    fn radix(&self) -> u32 {
+        self.style.radix()
+    }
+}
+
+impl<S: Style> Modifier for Wrap<S> {
+    type Inner = S;
+
+    fn apply<T>(&self, f: &mut dyn Write, data: &T) -> Result
+    where
+        T: ?Sized,
+        S: Formats<T>,
+    {
+        let mut f = Wrapper {
+            f,
+            width: self.width,
+            indent: self.indent,
+            hang: self.hang,
+            hyphenate: self.hyphenate,
+            measure: self.measure,
+            counter: WidthCounter::new(self.measure),
+            col: 0,
+            start: 0,
+            empty: true,
+            paragraph: true,
+            spaces: 0,
+            buf: [0; WORD_LEN],
+            len: 0,
+            word: 0,
+            streaming: false,
+            held: None,
+        };
+
+        self.style.format(&mut f, data)?;
+        f.end_word()
+    }
+}
+
+/// The size of the buffer words are held in until they're placed.
+const WORD_LEN: usize = 64;
+
+/// Wraps text as it's written.
+struct Wrapper<'w> {
+    f: &'w mut dyn Write,
+    width: usize,
+    indent: usize,
+    hang: usize,
+    hyphenate: bool,
+    measure: Measure,
+
+    counter: WidthCounter,
+
+    /// The current column.
+    col: usize,
+
+    /// The column this line's content starts at, after indentation.
+    start: usize,
+
+    /// Whether or not nothing has been written to this line yet.
+    empty: bool,
+
+    /// Whether or not this line starts a paragraph.
+    paragraph: bool,
+
+    /// The whitespace before the current word.
+    spaces: usize,
+
+    /// The current word, until it's placed.
+    buf: [u8; WORD_LEN],
+    len: usize,
+
+    /// The width of the current word.
+    word: usize,
+
+    /// Whether or not the current word has been placed, and is being written
+    /// as it comes.
+    streaming: bool,
+
+    /// When streaming, the last character and its width, held back until
+    /// it's known whether or not it's the end of the word.
+    held: Option<(char, usize)>,
+}
+
+impl Wrapper<'_> {
+    /// Start a new line, created by wrapping.
+    fn wrap(&mut self) -> Result {
+        self.f.write_char('\n')?;
+        self.col = 0;
+        self.empty = true;
+        self.paragraph = false;
+
+        Ok(())
+    }
+
+    /// Write the indentation and whitespace before a word, wrapping first if
+    /// it doesn't fit.
+    fn place(&mut self, width: usize) -> Result {
+        if !self.empty && self.col + self.spaces + width > self.width {
+            self.wrap()?;
+        }
+
+        let spaces = if !self.empty {
+            self.spaces
+        } else if self.paragraph {
+            self.indent + self.spaces
+        } else {
+            self.hang
+        };
+
+        for _ in 0..spaces {
+            self.f.write_char(' ')?;
+        }
+
+        self.col += spaces;
+        if self.empty {
+            self.start = self.col;
+        }
+
+        self.spaces = 0;
+        self.empty = false;
+
+        Ok(())
+    }
+
+    /// Start streaming the current word, writing whatever was buffered.
+    fn stream(&mut self) -> Result {
+        // the word's full width isn't known yet, so only wrap if it's already
+        // too wide for this line
+        self.place(self.word)?;
+        self.streaming = true;
+
+        let buf = self.buf;
+        let word = core::str::from_utf8(&buf[..self.len]).map_err(|_| Error)?;
+        self.len = 0;
+
+        // the start of a word is always the start of a cluster
+        let mut counter = WidthCounter::new(self.measure);
+        for ch in word.chars() {
+            let width = counter.push(ch);
+            self.stream_char(ch, width)?;
+        }
+
+        Ok(())
+    }
+
+    fn stream_char(&mut self, ch: char, width: usize) -> Result {
+        if let Some((held, held_width)) = self.held.replace((ch, width)) {
+            self.write_streamed(held, held_width, false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a character of a streamed word, breaking the word first if
+    /// hyphenating and it (and a hyphen, if it isn't the last) won't fit.
+    fn write_streamed(&mut self, ch: char, width: usize, last: bool) -> Result {
+        let needs = width + !last as usize;
+        if self.hyphenate && width != 0 && self.col + needs > self.width && self.col > self.start {
+            self.f.write_char('-')?;
+            self.wrap()?;
+            self.place(0)?;
+        }
+
+        self.col += width;
+        self.f.write_char(ch)
+    }
+
+    /// Finish off the current word, placing it if it hasn't been.
+    fn end_word(&mut self) -> Result {
+        if self.streaming {
+            if let Some((ch, width)) = self.held.take() {
+                self.write_streamed(ch, width, true)?;
+            }
+
+            self.streaming = false;
+        } else if self.len != 0 {
+            self.place(self.word)?;
+
+            let word = core::str::from_utf8(&self.buf[..self.len]).map_err(|_| Error)?;
+            self.f.write_str(word)?;
+            self.col += self.word;
+            self.len = 0;
+        }
+
+        self.word = 0;
+        Ok(())
+    }
+}
+
+impl Write for Wrapper<'_> {
+    fn write_str(&mut self, data: &str) -> Result {
+        for ch in data.chars() {
+            self.write_char(ch)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> Result {
+        let width = self.counter.push(ch);
+
+        if ch == '\n' {
+            self.end_word()?;
+            self.f.write_char('\n')?;
+
+            self.col = 0;
+            self.empty = true;
+            self.paragraph = true;
+            self.spaces = 0;
+        } else if ch.is_whitespace() && !matches!(ch, '\u{a0}' | '\u{2007}' | '\u{202f}') {
+            self.end_word()?;
+            self.spaces += 1;
+        } else if self.streaming {
+            self.word += width;
+            self.stream_char(ch, width)?;
+        } else {
+            self.word += width;
+
+            // too long to hold, or to fit on any line
+            let hang = if self.empty && self.paragraph {
+                self.indent
+            } else {
+                self.hang
+            };
+            let long = self.word + hang > self.width;
+
+            if self.len + ch.len_utf8() > WORD_LEN || long {
+                self.stream()?;
+                self.stream_char(ch, width)?;
+            } else {
+                ch.encode_utf8(&mut self.buf[self.len..]);
+                self.len += ch.len_utf8();
+            }
+        }
+
+        Ok(())
+    }
+}
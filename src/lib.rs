@@ -5,6 +5,9 @@
 #[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
+// lets `restd-derive`'s output, which uses `::restd` paths, work in here too
+extern crate self as restd;
+
 pub mod co;
 pub mod fmt;
 pub mod io;